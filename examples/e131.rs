@@ -7,11 +7,13 @@ use stagebridge::dmx::Device;
 use stagebridge::e131::E131;
 
 fn main() -> Result<()> {
-    let pars = [Par { color: Rgbw::WHITE, alpha: 1.0 }; 10];
+    let pars = [Par { color: Rgbw::WHITE, alpha: 1.0, ..Default::default() }; 10];
     let dmx = {
-        let mut channels = vec![0; 8 * pars.len() + 1];
-        for (i, par) in pars.iter().enumerate() {
-            par.encode(&mut channels[1 + 8 * i..]);
+        let mut channels = vec![0; 1];
+        for par in &pars {
+            let i = channels.len();
+            channels.resize(i + par.channels(), 0);
+            par.encode(&mut channels[i..]);
         }
         channels
     };
@@ -0,0 +1,28 @@
+use anyhow::{Context, Result};
+
+use stagebridge::dmx::gdtf::{Fixture, FixtureType};
+use stagebridge::dmx::Device;
+
+fn main() -> Result<()> {
+    let path = std::env::args().nth(1).context("usage: gdtf <file.gdtf>")?;
+    let ty = FixtureType::open(path)?;
+    println!("{} - {}", ty.manufacturer, ty.name);
+
+    for mode in &ty.modes {
+        println!("\n{} ({} channels)", mode.name, mode.footprint());
+        for channel in &mode.channels {
+            println!("  {:?} {}.{}", channel.offset, channel.geometry, channel.attribute);
+            for function in &channel.functions {
+                println!("    {}..={} {}", function.from, function.to, function.name);
+            }
+        }
+
+        let mut fixture = Fixture::new(&ty, &mode.name)?;
+        fixture.set("Dimmer", 1.0);
+        let mut buf = vec![0; fixture.channels()];
+        fixture.encode(&mut buf);
+        println!("  Dimmer at full: {buf:?}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Blend modes, for compositing effect layers before encoding, e.g.
+//! `Blend::Screen.over(base, flash, 0.5)`.
+
+use super::{Rgb, Rgbw};
+use crate::num::Interp;
+
+/// How a layer combines with the color beneath it, channel by channel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Blend {
+    /// Replace with the layer.
+    #[default]
+    Normal,
+    /// Sum, clipped at 1.
+    Add,
+    /// Product, which only darkens.
+    Multiply,
+    /// Inverted product of the inverses, which only lightens.
+    Screen,
+    /// Highest takes precedence, for HTP merging.
+    Max,
+    /// Lowest takes precedence.
+    Min,
+    /// Difference, clipped at 0.
+    Subtract,
+}
+
+/// Colors made of independent channels, which blend channel by channel.
+pub trait Channels: Copy {
+    /// Combine each pair of channels with `f`.
+    fn zip(self, other: Self, f: impl Fn(f64, f64) -> f64) -> Self;
+
+    /// Interpolate each channel towards `other`.
+    fn mix(self, other: Self, fr: f64) -> Self {
+        self.zip(other, |a, b| fr.lerp(a..b))
+    }
+}
+
+impl Blend {
+    /// Blend a layer onto a base color.
+    pub fn apply<C: Channels>(self, base: C, layer: C) -> C {
+        base.zip(layer, |a, b| match self {
+            Blend::Normal => b,
+            Blend::Add => (a + b).min(1.0),
+            Blend::Multiply => a * b,
+            Blend::Screen => 1.0 - (1.0 - a) * (1.0 - b),
+            Blend::Max => a.max(b),
+            Blend::Min => a.min(b),
+            Blend::Subtract => (a - b).max(0.0),
+        })
+    }
+
+    /// Blend a layer onto a base color with an opacity, where 0 leaves the base untouched.
+    pub fn over<C: Channels>(self, base: C, layer: C, alpha: f64) -> C {
+        base.mix(self.apply(base, layer), alpha)
+    }
+}
+
+impl Channels for Rgb {
+    fn zip(self, o: Self, f: impl Fn(f64, f64) -> f64) -> Self {
+        Rgb(f(self.0, o.0), f(self.1, o.1), f(self.2, o.2))
+    }
+}
+
+impl Channels for Rgbw {
+    fn zip(self, o: Self, f: impl Fn(f64, f64) -> f64) -> Self {
+        Rgbw(f(self.0, o.0), f(self.1, o.1), f(self.2, o.2), f(self.3, o.3))
+    }
+}
@@ -0,0 +1,130 @@
+//! Correlated color temperature, in Kelvin, with tint.
+//!
+//! Temperatures follow the Planckian locus using Kim et al's cubic approximation, valid from
+//! 1667K to 25000K. Tint is Duv, the distance from the locus in CIE 1960 uv, where positive is
+//! green and negative is magenta. Useful tints are small, around `-0.02..0.02`.
+
+use super::{Rgb, Rgbw, Xyy, Xyz};
+
+/// Range of temperatures the locus approximation covers.
+pub const KELVIN: std::ops::RangeInclusive<f64> = 1667.0..=25000.0;
+
+impl Xyy {
+    /// A color temperature and tint at a relative luminance.
+    pub fn kelvin(kelvin: f64, tint: f64, lum: f64) -> Self {
+        let (u, v) = locus(kelvin);
+        let (nu, nv) = normal(kelvin);
+        let (x, y) = uv_to_xy(u + tint * nu, v + tint * nv);
+        Xyy(x, y, lum)
+    }
+
+    /// Estimate the color temperature and tint, as `(kelvin, tint)`.
+    pub fn cct(self) -> (f64, f64) {
+        let (u, v) = xy_to_uv(self.0, self.1);
+        let dist = |kelvin: f64| {
+            let (lu, lv) = locus(kelvin);
+            (u - lu).powi(2) + (v - lv).powi(2)
+        };
+
+        // Search in mireds, which are closer to perceptually even than Kelvin.
+        let (mut lo, mut hi) = (1e6 / KELVIN.end(), 1e6 / KELVIN.start());
+        for _ in 0..64 {
+            let a = lo + (hi - lo) / 3.0;
+            let b = hi - (hi - lo) / 3.0;
+            if dist(1e6 / a) < dist(1e6 / b) {
+                hi = b;
+            } else {
+                lo = a;
+            }
+        }
+
+        let kelvin = 1e6 / ((lo + hi) / 2.0);
+        let (lu, lv) = locus(kelvin);
+        let (nu, nv) = normal(kelvin);
+        (kelvin, (u - lu) * nu + (v - lv) * nv)
+    }
+}
+
+impl Rgb {
+    /// A color temperature and tint, scaled so the brightest channel is 1.
+    ///
+    /// Very warm temperatures sit slightly outside sRGB, so channels that would dip below 0 are
+    /// clipped to 0.
+    pub fn kelvin(kelvin: f64, tint: f64) -> Self {
+        let Rgb(r, g, b) = Xyz::from(Xyy::kelvin(kelvin, tint, 1.0)).into();
+        let [r, g, b] = [r, g, b].map(|c| c.max(0.0));
+        let max = r.max(g).max(b);
+        if max <= 0.0 {
+            return Rgb::BLACK;
+        }
+        Rgb(r / max, g / max, b / max)
+    }
+
+    /// Estimate the color temperature and tint, as `(kelvin, tint)`.
+    pub fn cct(self) -> (f64, f64) {
+        Xyy::from(Xyz::from(self)).cct()
+    }
+}
+
+impl Rgbw {
+    /// A color temperature and tint, scaled so the brightest channel is 1.
+    pub fn kelvin(kelvin: f64, tint: f64) -> Self {
+        Rgb::kelvin(kelvin, tint).into()
+    }
+
+    /// Estimate the color temperature and tint, as `(kelvin, tint)`.
+    pub fn cct(self) -> (f64, f64) {
+        Xyy::from(Xyz::from(self)).cct()
+    }
+}
+
+/// Point on the Planckian locus in CIE 1960 uv.
+fn locus(kelvin: f64) -> (f64, f64) {
+    let t = kelvin.clamp(*KELVIN.start(), *KELVIN.end());
+    let (t1, t2, t3) = (1e3 / t, 1e6 / (t * t), 1e9 / (t * t * t));
+
+    let x = if t <= 4000.0 {
+        -0.2661239 * t3 - 0.2343589 * t2 + 0.8776956 * t1 + 0.179910
+    } else {
+        -3.0258469 * t3 + 2.1070379 * t2 + 0.2226347 * t1 + 0.240390
+    };
+
+    let (x2, x3) = (x * x, x * x * x);
+    let y = if t <= 2222.0 {
+        -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+    } else if t <= 4000.0 {
+        -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+    } else {
+        3.0817580 * x3 - 5.87338670 * x2 + 3.75112997 * x - 0.37001483
+    };
+
+    xy_to_uv(x, y)
+}
+
+/// Unit normal to the locus in uv, pointing towards green.
+fn normal(kelvin: f64) -> (f64, f64) {
+    let (u0, v0) = locus(kelvin - 1.0);
+    let (u1, v1) = locus(kelvin + 1.0);
+    let (du, dv) = (u1 - u0, v1 - v0);
+    let len = du.hypot(dv);
+    if len > 0.0 {
+        let (nu, nv) = (-dv / len, du / len);
+        if nv < 0.0 {
+            (-nu, -nv)
+        } else {
+            (nu, nv)
+        }
+    } else {
+        (0.0, 1.0)
+    }
+}
+
+fn xy_to_uv(x: f64, y: f64) -> (f64, f64) {
+    let d = -2.0 * x + 12.0 * y + 3.0;
+    (4.0 * x / d, 6.0 * y / d)
+}
+
+fn uv_to_xy(u: f64, v: f64) -> (f64, f64) {
+    let d = 2.0 * u - 8.0 * v + 4.0;
+    (3.0 * u / d, 2.0 * v / d)
+}
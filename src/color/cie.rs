@@ -0,0 +1,199 @@
+//! Calibrated color in CIE XYZ and xyY.
+//!
+//! [`Rgb`] and [`Rgbw`] are channel levels, so the same value looks different on every brand of
+//! fixture. A [`Calibration`] describes the measured chromaticity and output of a fixture's emitters,
+//! which lets [`Calibration::solve`] pick levels that produce a given [`Xyz`] color on that fixture.
+//!
+//! [`Rgb`] converts to and from [`Xyz`] as linear sRGB, without clamping, so calibrated colors outside
+//! the sRGB gamut survive the trip through a driver's `Rgbw` color field.
+
+use super::{Emitter, Emitters, Rgb, Rgbw};
+use crate::num::Vec3;
+
+/// A CIE 1931 XYZ color, with `Y` as relative luminance where 1 is full brightness.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct Xyz(pub f64, pub f64, pub f64);
+
+/// A CIE xyY color: `x, y` chromaticity and `Y` relative luminance.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct Xyy(pub f64, pub f64, pub f64);
+
+/// A measured emitter: its `x, y` chromaticity and luminous flux at full.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Primary {
+    pub x: f64,
+    pub y: f64,
+    pub lumens: f64,
+}
+
+/// Measured emitters of a fixture, for solving emitter levels from a calibrated color.
+///
+/// White and amber are optional, and emitters without a measurement are left off.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Calibration {
+    pub red: Primary,
+    pub green: Primary,
+    pub blue: Primary,
+    pub white: Option<Primary>,
+    pub amber: Option<Primary>,
+    /// Luminous flux treated as `Y = 1`.
+    ///
+    /// Give fixtures that should match the same value here, e.g. that of the dimmest one,
+    /// so the same color comes out at the same brightness everywhere.
+    pub lumens: f64,
+}
+
+/// D65 white point chromaticity.
+pub const D65: (f64, f64) = (0.3127, 0.3290);
+
+impl Xyz {
+    /// Chromaticity coordinates, or the D65 white point for black.
+    pub fn xy(self) -> (f64, f64) {
+        let Xyz(x, y, z) = self;
+        let sum = x + y + z;
+        if sum > 0.0 {
+            (x / sum, y / sum)
+        } else {
+            D65
+        }
+    }
+
+    fn vec(self) -> Vec3 {
+        Vec3::new(self.0, self.1, self.2)
+    }
+}
+
+impl Primary {
+    pub fn new(x: f64, y: f64, lumens: f64) -> Self {
+        Self { x, y, lumens }
+    }
+
+    /// Tristimulus values of the emitter at full, in lumens.
+    fn vec(self) -> Vec3 {
+        let Xyz(x, y, z) = Xyy(self.x, self.y, self.lumens).into();
+        Vec3::new(x, y, z)
+    }
+}
+
+impl Calibration {
+    fn primary(&self, emitter: Emitter) -> Option<Primary> {
+        match emitter {
+            Emitter::Red => Some(self.red),
+            Emitter::Green => Some(self.green),
+            Emitter::Blue => Some(self.blue),
+            Emitter::White => self.white,
+            Emitter::Amber => self.amber,
+            Emitter::Uv => None,
+        }
+    }
+
+    /// Solve for the level of each emitter, in order, to produce a color on this fixture.
+    ///
+    /// Like [`Emitters::map`], white and amber are extracted first, as much as the red, green and
+    /// blue emitters can still make up the rest. Colors outside the fixture's gamut are clipped by
+    /// leaving out the negative light they'd need, and colors too bright for it are scaled down.
+    pub fn solve(&self, emitters: Emitters, color: Xyz) -> [f64; Emitters::MAX] {
+        let (r, g, b) = (self.red.vec(), self.green.vec(), self.blue.vec());
+
+        // Invert the matrix with the primaries as columns, so `inv * v` gives RGB levels for `v`.
+        let det = r.dot(g.cross(b));
+        if det.abs() < f64::EPSILON {
+            return emitters.map(Rgb::from(color).into());
+        }
+        let inv = [g.cross(b), b.cross(r), r.cross(g)].map(|row| row * (1.0 / det));
+        let solve = |v: Vec3| inv.map(|row| row.dot(v));
+
+        let mut levels = [0.0; Emitters::MAX];
+        let mut rest = color.vec() * self.lumens;
+
+        for extract in [Emitter::White, Emitter::Amber] {
+            let (Some(i), Some(p)) = (emitters.0.iter().position(|&e| e == extract), self.primary(extract)) else {
+                continue;
+            };
+            let v = p.vec();
+            let (s, d) = (solve(rest), solve(v));
+            let level = (0..3).filter(|&c| d[c] > 0.0).map(|c| s[c] / d[c]).fold(1.0, f64::min).max(0.0);
+            rest -= v * level;
+            levels[i] = level;
+        }
+
+        let rgb = solve(rest);
+        for (i, &e) in emitters.0.iter().enumerate().take(Emitters::MAX) {
+            match e {
+                Emitter::Red => levels[i] = rgb[0].max(0.0),
+                Emitter::Green => levels[i] = rgb[1].max(0.0),
+                Emitter::Blue => levels[i] = rgb[2].max(0.0),
+                Emitter::White | Emitter::Amber | Emitter::Uv => {}
+            }
+        }
+
+        let max = levels.iter().copied().fold(0.0, f64::max);
+        if max > 1.0 {
+            levels = levels.map(|l| l / max);
+        }
+        levels
+    }
+}
+
+/// Conversions
+mod conv {
+    use super::*;
+
+    impl From<Xyy> for Xyz {
+        fn from(Xyy(x, y, l): Xyy) -> Self {
+            if y > 0.0 {
+                Xyz(x * l / y, l, (1.0 - x - y) * l / y)
+            } else {
+                Xyz(0.0, 0.0, 0.0)
+            }
+        }
+    }
+
+    impl From<Xyz> for Xyy {
+        fn from(xyz: Xyz) -> Self {
+            let (x, y) = xyz.xy();
+            Xyy(x, y, xyz.1)
+        }
+    }
+
+    impl From<Rgb> for Xyz {
+        /// Convert linear sRGB -> XYZ.
+        fn from(Rgb(r, g, b): Rgb) -> Self {
+            Xyz(
+                0.4124 * r + 0.3576 * g + 0.1805 * b,
+                0.2126 * r + 0.7152 * g + 0.0722 * b,
+                0.0193 * r + 0.1192 * g + 0.9505 * b,
+            )
+        }
+    }
+
+    impl From<Xyz> for Rgb {
+        /// Convert XYZ -> linear sRGB, leaving out of gamut channels outside `0.0..1.0`.
+        fn from(Xyz(x, y, z): Xyz) -> Self {
+            Rgb(
+                3.2406 * x - 1.5372 * y - 0.4986 * z,
+                -0.9689 * x + 1.8758 * y + 0.0415 * z,
+                0.0557 * x - 0.2040 * y + 1.0570 * z,
+            )
+        }
+    }
+
+    impl From<Rgbw> for Xyz {
+        /// Convert Rgbw -> XYZ, treating white as equal parts red, green and blue.
+        fn from(Rgbw(r, g, b, w): Rgbw) -> Self {
+            Rgb(r + w, g + w, b + w).into()
+        }
+    }
+
+    impl From<Xyz> for Rgbw {
+        fn from(xyz: Xyz) -> Self {
+            Rgb::from(xyz).into()
+        }
+    }
+
+    impl From<Xyy> for Rgbw {
+        fn from(xyy: Xyy) -> Self {
+            Xyz::from(xyy).into()
+        }
+    }
+}
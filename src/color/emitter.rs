@@ -0,0 +1,109 @@
+//! Mapping colors onto the emitters of a fixture.
+//!
+//! Effects describe the color they want to see, and each fixture maps that onto whatever LEDs it
+//! has. White light from `Rgbw(0, 0, 0, 1)` comes out of the RGB emitters on a fixture without a
+//! white emitter, and `Rgb::WHITE` comes out of the white emitter on a fixture with one, so the
+//! same color looks roughly the same everywhere.
+
+use super::{Rgb, Rgbw};
+
+/// A kind of LED emitter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Emitter {
+    Red,
+    Green,
+    Blue,
+    White,
+    Amber,
+    Uv,
+}
+
+impl Emitter {
+    /// Approximate appearance of the emitter at full, or `None` for UV which isn't visible.
+    pub fn rgb(self) -> Option<Rgb> {
+        match self {
+            Emitter::Red => Some(Rgb(1.0, 0.0, 0.0)),
+            Emitter::Green => Some(Rgb(0.0, 1.0, 0.0)),
+            Emitter::Blue => Some(Rgb(0.0, 0.0, 1.0)),
+            Emitter::White => Some(Rgb(1.0, 1.0, 1.0)),
+            Emitter::Amber => Some(Rgb(1.0, 0.75, 0.0)),
+            Emitter::Uv => None,
+        }
+    }
+}
+
+/// The emitters of a fixture, in channel order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Emitters(pub &'static [Emitter]);
+
+impl Emitters {
+    /// The most emitters a fixture can have.
+    pub const MAX: usize = 6;
+
+    pub const RGB: Self = Self(&[Emitter::Red, Emitter::Green, Emitter::Blue]);
+    pub const RGBW: Self = Self(&[Emitter::Red, Emitter::Green, Emitter::Blue, Emitter::White]);
+    pub const RGBA: Self = Self(&[Emitter::Red, Emitter::Green, Emitter::Blue, Emitter::Amber]);
+    pub const RGBAW: Self = Self(&[Emitter::Red, Emitter::Green, Emitter::Blue, Emitter::Amber, Emitter::White]);
+    pub const RGBAW_UV: Self = Self(&[
+        Emitter::Red,
+        Emitter::Green,
+        Emitter::Blue,
+        Emitter::Amber,
+        Emitter::White,
+        Emitter::Uv,
+    ]);
+
+    /// Map a color onto a level for each emitter, in order. Levels past the number of emitters are 0.
+    pub fn map(self, color: Rgbw) -> [f64; Self::MAX] {
+        self.map_uv(color, 0.0)
+    }
+
+    /// Map a color plus a UV level onto a level for each emitter, in order.
+    ///
+    /// On a fixture with a white emitter, the white shared by red, green and blue is extracted
+    /// and added to `w`; without one, `w` goes into red, green and blue. Then amber is extracted as
+    /// much as possible, with the primaries making up the rest. Each channel is clipped into
+    /// `0.0..1.0` on its own.
+    pub fn map_uv(self, color: Rgbw, uv: f64) -> [f64; Self::MAX] {
+        let Rgbw(r, g, b, w) = color;
+        let white = self.0.iter().position(|&e| e == Emitter::White);
+        let rgb = [r, g, b].map(|c| c.max(0.0));
+        let (mut rgb, w) = match white {
+            Some(_) => {
+                let min = rgb[0].min(rgb[1]).min(rgb[2]);
+                (rgb.map(|c| c - min), w + min)
+            }
+            None => (rgb.map(|c| c + w.max(0.0)), 0.0),
+        };
+        rgb = rgb.map(|c| c.clamp(0.0, 1.0));
+
+        let mut levels = [0.0; Self::MAX];
+
+        if let Some(i) = white {
+            levels[i] = w.clamp(0.0, 1.0);
+        }
+
+        // Extract amber first, then fill in the primaries.
+        if let Some(i) = self.0.iter().position(|&e| e == Emitter::Amber) {
+            let Rgb(er, eg, eb) = Emitter::Amber.rgb().unwrap();
+            let e = [er, eg, eb];
+            let level = (0..3).filter(|&c| e[c] > 0.0).map(|c| rgb[c] / e[c]).fold(1.0, f64::min);
+            for (c, e) in rgb.iter_mut().zip(e) {
+                *c = (*c - level * e).max(0.0);
+            }
+            levels[i] = level;
+        }
+
+        for (i, &e) in self.0.iter().enumerate().take(Self::MAX) {
+            match e {
+                Emitter::Red => levels[i] = rgb[0],
+                Emitter::Green => levels[i] = rgb[1],
+                Emitter::Blue => levels[i] = rgb[2],
+                Emitter::Uv => levels[i] = uv.clamp(0.0, 1.0),
+                Emitter::White | Emitter::Amber => {}
+            }
+        }
+
+        levels
+    }
+}
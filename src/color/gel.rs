@@ -0,0 +1,208 @@
+//! Theatrical gels, as color presets.
+//!
+//! Colors are rough approximations of each gel over a tungsten source, good enough to match
+//! an LD's intent rather than the gel itself.
+
+use super::{Rgb, Rgbw, Xyz};
+use std::fmt;
+
+/// Gel manufacturer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Maker {
+    Lee,
+    Rosco,
+}
+
+/// A gel from the built-in library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gel {
+    pub maker: Maker,
+    pub number: u16,
+    pub name: &'static str,
+    /// Approximate color as `0xrrggbb`.
+    pub hex: u32,
+}
+
+impl Maker {
+    /// Prefix for gel codes, e.g. `L` for `L201`.
+    pub fn prefix(self) -> char {
+        match self {
+            Maker::Lee => 'L',
+            Maker::Rosco => 'R',
+        }
+    }
+}
+
+impl Gel {
+    /// Every gel in the library.
+    pub const ALL: &'static [Gel] = GELS;
+
+    const fn new(maker: Maker, number: u16, name: &'static str, hex: u32) -> Self {
+        Self { maker, number, name, hex }
+    }
+
+    /// Look up a gel by code, e.g. `L201`, `R80` or `Lee 201`.
+    pub fn find(code: &str) -> Option<&'static Gel> {
+        let code = code.trim();
+        let (maker, number) = [
+            ("lee", Maker::Lee),
+            ("rosco", Maker::Rosco),
+            ("l", Maker::Lee),
+            ("r", Maker::Rosco),
+        ]
+        .into_iter()
+        .find_map(|(prefix, maker)| {
+            let rest = code.get(..prefix.len())?.eq_ignore_ascii_case(prefix).then(|| &code[prefix.len()..])?;
+            Some((maker, rest.trim().parse::<u16>().ok()?))
+        })?;
+        GELS.iter().find(|g| g.maker == maker && g.number == number)
+    }
+
+    /// Gels whose name contains `query`, ignoring case.
+    pub fn search(query: &str) -> impl Iterator<Item = &'static Gel> + '_ {
+        let query = query.to_ascii_lowercase();
+        GELS.iter().filter(move |g| g.name.to_ascii_lowercase().contains(&query))
+    }
+
+    pub fn rgb(&self) -> Rgb {
+        let byte = |shift: u32| ((self.hex >> shift) & 0xff) as f64 / 255.0;
+        Rgb(byte(16), byte(8), byte(0))
+    }
+
+    pub fn rgbw(&self) -> Rgbw {
+        self.rgb().into()
+    }
+
+    pub fn xyz(&self) -> Xyz {
+        self.rgb().into()
+    }
+}
+
+impl fmt::Display for Gel {
+    /// Format as a code like `L201` or `R02`, zero padded the way each maker numbers them.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = match self.maker {
+            Maker::Lee => 3,
+            Maker::Rosco => 2,
+        };
+        write!(f, "{}{:0width$}", self.maker.prefix(), self.number)
+    }
+}
+
+/// Conversions
+mod conv {
+    use super::*;
+
+    impl From<&Gel> for Rgb {
+        fn from(gel: &Gel) -> Self {
+            gel.rgb()
+        }
+    }
+
+    impl From<&Gel> for Rgbw {
+        fn from(gel: &Gel) -> Self {
+            gel.rgbw()
+        }
+    }
+
+    impl From<&Gel> for Xyz {
+        fn from(gel: &Gel) -> Self {
+            gel.xyz()
+        }
+    }
+}
+
+#[rustfmt::skip]
+const GELS: &[Gel] = &[
+    Gel::new(Maker::Lee, 2, "Rose Pink", 0xff3fa0),
+    Gel::new(Maker::Lee, 3, "Lavender Tint", 0xf4e6ff),
+    Gel::new(Maker::Lee, 7, "Pale Yellow", 0xfffcc4),
+    Gel::new(Maker::Lee, 8, "Dark Salmon", 0xff8a5c),
+    Gel::new(Maker::Lee, 9, "Pale Amber Gold", 0xffd9a0),
+    Gel::new(Maker::Lee, 10, "Medium Yellow", 0xffe600),
+    Gel::new(Maker::Lee, 13, "Straw Tint", 0xffe9b8),
+    Gel::new(Maker::Lee, 17, "Surprise Peach", 0xe8a08a),
+    Gel::new(Maker::Lee, 19, "Fire", 0xff3a00),
+    Gel::new(Maker::Lee, 20, "Medium Amber", 0xffaa4a),
+    Gel::new(Maker::Lee, 21, "Gold Amber", 0xff8a2a),
+    Gel::new(Maker::Lee, 24, "Scarlet", 0xff2a4a),
+    Gel::new(Maker::Lee, 26, "Bright Red", 0xe0001a),
+    Gel::new(Maker::Lee, 27, "Medium Red", 0xb00010),
+    Gel::new(Maker::Lee, 29, "Plasa Red", 0xc8003a),
+    Gel::new(Maker::Lee, 35, "Light Pink", 0xffc0d0),
+    Gel::new(Maker::Lee, 36, "Medium Pink", 0xff90b0),
+    Gel::new(Maker::Lee, 48, "Rose Purple", 0xd070d8),
+    Gel::new(Maker::Lee, 49, "Medium Purple", 0xa000a0),
+    Gel::new(Maker::Lee, 53, "Paler Lavender", 0xe8e0ff),
+    Gel::new(Maker::Lee, 58, "Lavender", 0xa070ff),
+    Gel::new(Maker::Lee, 61, "Mist Blue", 0xd8ecff),
+    Gel::new(Maker::Lee, 63, "Pale Blue", 0xc0e0ff),
+    Gel::new(Maker::Lee, 68, "Sky Blue", 0x3aa0ff),
+    Gel::new(Maker::Lee, 71, "Tokyo Blue", 0x1000a0),
+    Gel::new(Maker::Lee, 79, "Just Blue", 0x2050ff),
+    Gel::new(Maker::Lee, 85, "Deeper Blue", 0x0030c0),
+    Gel::new(Maker::Lee, 89, "Moss Green", 0x30d070),
+    Gel::new(Maker::Lee, 90, "Dark Yellow Green", 0x008a20),
+    Gel::new(Maker::Lee, 101, "Yellow", 0xfff000),
+    Gel::new(Maker::Lee, 103, "Straw", 0xfff0c8),
+    Gel::new(Maker::Lee, 104, "Deep Amber", 0xffc000),
+    Gel::new(Maker::Lee, 105, "Orange", 0xff8a00),
+    Gel::new(Maker::Lee, 106, "Primary Red", 0xe00000),
+    Gel::new(Maker::Lee, 115, "Peacock Blue", 0x00c0b0),
+    Gel::new(Maker::Lee, 116, "Medium Blue-Green", 0x00a098),
+    Gel::new(Maker::Lee, 117, "Steel Blue", 0xb8f0ff),
+    Gel::new(Maker::Lee, 118, "Light Blue", 0x30d0ff),
+    Gel::new(Maker::Lee, 119, "Dark Blue", 0x0020c8),
+    Gel::new(Maker::Lee, 120, "Deep Blue", 0x0010a0),
+    Gel::new(Maker::Lee, 121, "Lee Green", 0xa0ff30),
+    Gel::new(Maker::Lee, 124, "Dark Green", 0x00c040),
+    Gel::new(Maker::Lee, 126, "Mauve", 0xc000a0),
+    Gel::new(Maker::Lee, 128, "Bright Pink", 0xff1a90),
+    Gel::new(Maker::Lee, 132, "Medium Blue", 0x0070ff),
+    Gel::new(Maker::Lee, 134, "Golden Amber", 0xff9060),
+    Gel::new(Maker::Lee, 135, "Deep Golden Amber", 0xff5a00),
+    Gel::new(Maker::Lee, 136, "Pale Lavender", 0xe8c8ff),
+    Gel::new(Maker::Lee, 139, "Primary Green", 0x00a000),
+    Gel::new(Maker::Lee, 147, "Apricot", 0xff9a6a),
+    Gel::new(Maker::Lee, 151, "Gold Tint", 0xffd0c0),
+    Gel::new(Maker::Lee, 152, "Pale Gold", 0xffd8b0),
+    Gel::new(Maker::Lee, 154, "Pale Rose", 0xffd8d0),
+    Gel::new(Maker::Lee, 158, "Deep Orange", 0xff6a00),
+    Gel::new(Maker::Lee, 164, "Flame Red", 0xff2000),
+    Gel::new(Maker::Lee, 170, "Deep Lavender", 0xd8a0ff),
+    Gel::new(Maker::Lee, 181, "Congo Blue", 0x2a0090),
+    Gel::new(Maker::Lee, 195, "Zenith Blue", 0x0030d0),
+    Gel::new(Maker::Lee, 200, "Double CT Blue", 0x9ab8ff),
+    Gel::new(Maker::Lee, 201, "Full CT Blue", 0xb8d0ff),
+    Gel::new(Maker::Lee, 202, "Half CT Blue", 0xd8e4ff),
+    Gel::new(Maker::Lee, 203, "Quarter CT Blue", 0xeaf0ff),
+    Gel::new(Maker::Lee, 204, "Full CT Orange", 0xffb070),
+    Gel::new(Maker::Lee, 205, "Half CT Orange", 0xffcc99),
+    Gel::new(Maker::Lee, 206, "Quarter CT Orange", 0xffe0c0),
+
+    Gel::new(Maker::Rosco, 2, "Bastard Amber", 0xffcfa0),
+    Gel::new(Maker::Rosco, 8, "Pale Gold", 0xffe0b0),
+    Gel::new(Maker::Rosco, 10, "Medium Yellow", 0xfff000),
+    Gel::new(Maker::Rosco, 21, "Golden Amber", 0xff9a3a),
+    Gel::new(Maker::Rosco, 23, "Orange", 0xff7a00),
+    Gel::new(Maker::Rosco, 26, "Light Red", 0xe00020),
+    Gel::new(Maker::Rosco, 27, "Medium Red", 0xb00010),
+    Gel::new(Maker::Rosco, 33, "No Color Pink", 0xffc8d0),
+    Gel::new(Maker::Rosco, 44, "Middle Rose", 0xff60b0),
+    Gel::new(Maker::Rosco, 47, "Light Rose Purple", 0xd080d0),
+    Gel::new(Maker::Rosco, 54, "Special Lavender", 0xe8d8ff),
+    Gel::new(Maker::Rosco, 58, "Deep Lavender", 0xa060ff),
+    Gel::new(Maker::Rosco, 59, "Indigo", 0x3000a0),
+    Gel::new(Maker::Rosco, 60, "No Color Blue", 0xd0e8ff),
+    Gel::new(Maker::Rosco, 64, "Light Steel Blue", 0x90d0ff),
+    Gel::new(Maker::Rosco, 68, "Sky Blue", 0x4090ff),
+    Gel::new(Maker::Rosco, 74, "Night Blue", 0x2030c0),
+    Gel::new(Maker::Rosco, 80, "Primary Blue", 0x0040ff),
+    Gel::new(Maker::Rosco, 83, "Medium Blue", 0x0030c0),
+    Gel::new(Maker::Rosco, 89, "Moss Green", 0x40d060),
+    Gel::new(Maker::Rosco, 90, "Dark Yellow Green", 0x00a020),
+    Gel::new(Maker::Rosco, 91, "Primary Green", 0x008000),
+    Gel::new(Maker::Rosco, 95, "Medium Blue Green", 0x00a0a0),
+    Gel::new(Maker::Rosco, 3202, "Full Blue", 0xb0c8ff),
+    Gel::new(Maker::Rosco, 3407, "Roscosun CTO", 0xffb060),
+];
@@ -0,0 +1,95 @@
+//! Gradients, for mapping a float like `t.ramp(pd)` onto colors.
+
+use super::{Channels, Hsv, Rgb};
+use crate::num::Interp;
+
+/// Color space to interpolate between stops in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Space {
+    /// Straight through channel levels.
+    #[default]
+    Rgb,
+    /// Perceptually even brightness.
+    OkLab,
+    /// Perceptually even, around the hue circle rather than through grey.
+    OkLch,
+    /// Around the hue circle.
+    Hsv,
+}
+
+/// How a gradient continues outside `0.0..1.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Extend {
+    /// Hold the first and last stops.
+    #[default]
+    Clamp,
+    /// Start over from the first stop.
+    Repeat,
+    /// Go back and forth.
+    Mirror,
+}
+
+/// Colors at positions from `0.0..1.0`, interpolated between.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Gradient {
+    /// `(position, color)` pairs, sorted by position.
+    pub stops: Vec<(f64, Rgb)>,
+    pub space: Space,
+    pub extend: Extend,
+}
+
+impl Space {
+    /// Interpolate between two colors in this space.
+    pub fn mix(self, a: Rgb, b: Rgb, fr: f64) -> Rgb {
+        match self {
+            Space::Rgb => a.mix(b, fr),
+            Space::OkLab => a.mix_oklab(b, fr),
+            Space::OkLch => a.mix_oklch(b, fr),
+            Space::Hsv => Hsv::from(a).mix(b.into(), fr).into(),
+        }
+    }
+}
+
+impl Extend {
+    /// Bring a position into `0.0..1.0`.
+    pub fn apply(self, fr: f64) -> f64 {
+        match self {
+            Extend::Clamp => fr.clamp(0.0, 1.0),
+            Extend::Repeat => fr.fmod(1.0),
+            Extend::Mirror => fr.tri(2.0),
+        }
+    }
+}
+
+impl Gradient {
+    /// A gradient from `(position, color)` stops, in any order.
+    pub fn new<I: IntoIterator<Item = (f64, Rgb)>>(stops: I) -> Self {
+        let mut stops: Vec<_> = stops.into_iter().collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { stops, ..Default::default() }
+    }
+
+    /// A gradient with colors spaced evenly from 0..1.
+    pub fn even<I: IntoIterator<Item = Rgb>>(colors: I) -> Self {
+        let colors: Vec<_> = colors.into_iter().collect();
+        let n = (colors.len().max(2) - 1) as f64;
+        Self::new(colors.into_iter().enumerate().map(|(i, c)| (i as f64 / n, c)))
+    }
+
+    /// The color at a position, or black for an empty gradient.
+    pub fn sample(&self, fr: f64) -> Rgb {
+        let fr = self.extend.apply(fr);
+        let i = self.stops.partition_point(|&(pos, _)| pos <= fr);
+        match (i.checked_sub(1).and_then(|i| self.stops.get(i)), self.stops.get(i)) {
+            (Some(&(p0, c0)), Some(&(p1, c1))) => self.space.mix(c0, c1, fr.ilerp(p0..p1)),
+            (Some(&(_, c)), None) | (None, Some(&(_, c))) => c,
+            (None, None) => Rgb::BLACK,
+        }
+    }
+}
+
+impl<const N: usize> From<[Rgb; N]> for Gradient {
+    fn from(colors: [Rgb; N]) -> Self {
+        Self::even(colors)
+    }
+}
@@ -1,7 +1,27 @@
 use crate::num::Interp;
 
+pub mod blend;
+pub mod cct;
+pub mod cie;
+pub mod emitter;
+pub mod gel;
+pub mod gradient;
+pub mod palette;
+mod parse;
+pub mod scheme;
+pub mod space;
+pub use blend::{Blend, Channels};
+pub use cie::{Calibration, Primary, Xyy, Xyz};
+pub use emitter::{Emitter, Emitters};
+pub use gel::Gel;
+pub use gradient::{Extend, Gradient, Space};
+pub use palette::Palette;
+pub use scheme::{Harmony, Picker};
+pub use space::{Hsl, Hsv, OkLab, OkLch};
+
 /// An (r, g, b) color.
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgb(pub f64, pub f64, pub f64);
 
 impl Rgb {
@@ -17,6 +37,7 @@ impl Rgb {
 
 /// An (r, g, b, w) color.
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgbw(pub f64, pub f64, pub f64, pub f64);
 
 /// Conversions
@@ -43,8 +64,34 @@ mod conv {
         }
     }
 
+    impl From<[f64; 3]> for Rgb {
+        fn from([r, g, b]: [f64; 3]) -> Self {
+            Self(r, g, b)
+        }
+    }
+
+    impl From<Rgb> for [f64; 3] {
+        fn from(Rgb(r, g, b): Rgb) -> Self {
+            [r, g, b]
+        }
+    }
+
+    impl From<[f64; 4]> for Rgbw {
+        fn from([r, g, b, w]: [f64; 4]) -> Self {
+            Self(r, g, b, w)
+        }
+    }
+
+    impl From<Rgbw> for [f64; 4] {
+        fn from(Rgbw(r, g, b, w): Rgbw) -> Self {
+            [r, g, b, w]
+        }
+    }
+
     impl From<Rgb> for Rgbw {
-        /// Convert Rgb -> Rgbw.
+        /// Convert Rgb -> Rgbw, leaving white at 0.
+        ///
+        /// Fixtures extract white when they encode, see [`Emitters::map`].
         fn from(Rgb(r, g, b): Rgb) -> Self {
             Self(r, g, b, 0.0)
         }
@@ -54,7 +101,7 @@ mod conv {
 /// Operators
 mod ops {
     use super::*;
-    use std::ops::{Add, AddAssign, Mul, MulAssign};
+    use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
     // Rgb * f64 -> Rgb, with each color channel scaled.
     impl Mul<f64> for Rgb {
@@ -109,6 +156,46 @@ mod ops {
             *self = *self + rhs;
         }
     }
+
+    // Normalized sum
+    impl Add<Rgb> for Rgb {
+        type Output = Rgb;
+        fn add(self, rhs: Rgb) -> Self::Output {
+            let Rgbw(r, g, b, _) = Rgbw::from(self) + Rgbw::from(rhs);
+            Rgb(r, g, b)
+        }
+    }
+    impl AddAssign for Rgb {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+
+    // Difference, clamped at 0
+    impl Sub<Rgb> for Rgb {
+        type Output = Rgb;
+        fn sub(self, rhs: Rgb) -> Self::Output {
+            Blend::Subtract.apply(self, rhs)
+        }
+    }
+    impl SubAssign for Rgb {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+
+    // Difference, clamped at 0
+    impl Sub<Rgbw> for Rgbw {
+        type Output = Rgbw;
+        fn sub(self, rhs: Rgbw) -> Self::Output {
+            Blend::Subtract.apply(self, rhs)
+        }
+    }
+    impl SubAssign for Rgbw {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
 }
 
 mod consts {
@@ -131,6 +218,14 @@ mod consts {
         pub const VIOLET:  Self = Self(0.533, 0.0,   1.0);
         pub const MAGENTA: Self = Self(1.0,   0.0,   1.0);
         pub const PINK:    Self = Self(1.0,   0.38,  0.8);
+
+        /// Each constant by its lowercase name.
+        pub const NAMED: &'static [(&'static str, Self)] = &[
+            ("black", Self::BLACK), ("white", Self::WHITE), ("rgb", Self::RGB), ("house", Self::HOUSE),
+            ("red", Self::RED), ("orange", Self::ORANGE), ("yellow", Self::YELLOW), ("pea", Self::PEA),
+            ("lime", Self::LIME), ("mint", Self::MINT), ("cyan", Self::CYAN), ("blue", Self::BLUE),
+            ("violet", Self::VIOLET), ("magenta", Self::MAGENTA), ("pink", Self::PINK),
+        ];
     }
 
     #[rustfmt::skip]
@@ -151,6 +246,14 @@ mod consts {
         pub const VIOLET:  Self = Self(0.533, 0.0,   1.0,   0.0);
         pub const MAGENTA: Self = Self(1.0,   0.0,   1.0,   0.0);
         pub const PINK:    Self = Self(1.0,   0.38,  0.8,   0.0);
+
+        /// Each constant by its lowercase name.
+        pub const NAMED: &'static [(&'static str, Self)] = &[
+            ("black", Self::BLACK), ("white", Self::WHITE), ("rgb", Self::RGB), ("rgbw", Self::RGBW),
+            ("house", Self::HOUSE), ("red", Self::RED), ("orange", Self::ORANGE), ("yellow", Self::YELLOW),
+            ("pea", Self::PEA), ("lime", Self::LIME), ("mint", Self::MINT), ("cyan", Self::CYAN),
+            ("blue", Self::BLUE), ("violet", Self::VIOLET), ("magenta", Self::MAGENTA), ("pink", Self::PINK),
+        ];
     }
 }
 
@@ -160,22 +263,9 @@ mod rand_ {
     use rand::Rng;
 
     impl Distribution<Rgb> for Standard {
+        /// Pick from the built-in `standard` palette, see [`Picker`] to avoid repeats.
         fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rgb {
-            match rng.gen_range(0..=11) {
-                0 => Rgb::RED,
-                1 => Rgb::ORANGE,
-                2 => Rgb::YELLOW,
-                3 => Rgb::PEA,
-                4 => Rgb::LIME,
-                5 => Rgb::MINT,
-                6 => Rgb::CYAN,
-                7 => Rgb::BLUE,
-                8 => Rgb::VIOLET,
-                9 => Rgb::MAGENTA,
-                10 => Rgb::PINK,
-                11 => Rgb::WHITE,
-                _ => unreachable!(),
-            }
+            Palette::builtin("standard").expect("Missing standard palette").sample(rng)
         }
     }
 }
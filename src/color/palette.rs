@@ -0,0 +1,110 @@
+//! Named palettes, built in or loaded from a file.
+
+use super::{Gradient, Rgb, Space};
+use crate::num::Interp;
+use anyhow::{bail, Context, Result};
+use rand::distributions::Distribution;
+use rand::Rng;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// A named list of colors.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Palette {
+    pub name: String,
+    pub colors: Vec<Rgb>,
+}
+
+impl Palette {
+    pub fn new<S: Into<String>, I: IntoIterator<Item = Rgb>>(name: S, colors: I) -> Self {
+        Self { name: name.into(), colors: colors.into_iter().collect() }
+    }
+
+    /// All the built-in palettes.
+    pub fn all_builtin() -> &'static [Palette] {
+        static BUILTIN: OnceLock<Vec<Palette>> = OnceLock::new();
+        BUILTIN.get_or_init(|| Self::parse(include_str!("palettes.txt")).expect("Invalid built-in palettes"))
+    }
+
+    /// A built-in palette by name, e.g. `"rainbow"`.
+    pub fn builtin(name: &str) -> Option<&'static Palette> {
+        Self::all_builtin().iter().find(|p| p.name == name)
+    }
+
+    /// Load palettes from a file, see [`Palette::parse`].
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<Palette>> {
+        let path = path.as_ref();
+        let s = std::fs::read_to_string(path).with_context(|| format!("Failed to read palettes {}", path.display()))?;
+        Self::parse(&s).with_context(|| format!("Failed to parse palettes {}", path.display()))
+    }
+
+    /// Parse palettes, each a `[name]` header followed by one color per line.
+    ///
+    /// Colors are anything [`Rgb`] parses from, e.g. `red` or `#ff8000`, or `r g b` levels from `0.0..1.0`.
+    /// Blank lines and lines starting with `//` are ignored, as are lines starting with `#` that
+    /// aren't hex colors.
+    pub fn parse(s: &str) -> Result<Vec<Palette>> {
+        let mut palettes: Vec<Palette> = vec![];
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") || (line.starts_with('#') && line.parse::<Rgb>().is_err()) {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                palettes.push(Palette::new(name.trim(), []));
+                continue;
+            }
+
+            let Some(palette) = palettes.last_mut() else {
+                bail!("Line {}: Color {line:?} before any [palette] header", i + 1);
+            };
+            palette.colors.push(parse_color(line).with_context(|| format!("Line {}", i + 1))?);
+        }
+        Ok(palettes)
+    }
+
+    /// The color at a position, stepping through the colors and wrapping around outside `0.0..1.0`.
+    pub fn pick(&self, fr: f64) -> Rgb {
+        let n = self.colors.len();
+        if n == 0 {
+            return Rgb::BLACK;
+        }
+        let i = (fr.fmod(1.0) * n as f64) as usize;
+        self.colors[i.min(n - 1)]
+    }
+
+    /// The color at a position, fading between the colors and looping back around to the first.
+    pub fn blend(&self, fr: f64, space: Space) -> Rgb {
+        let n = self.colors.len();
+        if n == 0 {
+            return Rgb::BLACK;
+        }
+        let x = fr.fmod(1.0) * n as f64;
+        let i = (x as usize).min(n - 1);
+        space.mix(self.colors[i], self.colors[(i + 1) % n], x - i as f64)
+    }
+
+    /// A gradient through the colors from 0..1.
+    pub fn gradient(&self) -> Gradient {
+        Gradient::even(self.colors.iter().copied())
+    }
+}
+
+impl Distribution<Rgb> for Palette {
+    /// Pick a color uniformly at random, or black for an empty palette.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rgb {
+        if self.colors.is_empty() {
+            return Rgb::BLACK;
+        }
+        self.colors[rng.gen_range(0..self.colors.len())]
+    }
+}
+
+fn parse_color(s: &str) -> Result<Rgb> {
+    let levels = s.split_whitespace().map(|v| v.parse::<f64>()).collect::<Result<Vec<_>, _>>();
+    match levels.as_deref() {
+        Ok(&[r, g, b]) => Ok(Rgb(r, g, b)),
+        _ => s.parse(),
+    }
+}
@@ -0,0 +1,282 @@
+//! Parsing colors from strings, and formatting them as hex.
+//!
+//! Accepts `#rgb`, `#rrggbb` and `#rrggbbww` hex, `rgb()`, `rgbw()` and `hsl()` functions, the crate's
+//! constant names like `house` or `PEA`, gel codes like `L201`, and CSS named colors. The crate's names
+//! take precedence, so e.g. `cyan` is [`Rgb::CYAN`] rather than CSS cyan, and `white` parses to
+//! [`Rgbw::WHITE`] as an `Rgbw`.
+
+use super::{Gel, Hsl, Rgb, Rgbw};
+use anyhow::{anyhow, bail, Context, Error, Result};
+use std::fmt;
+use std::str::FromStr;
+
+impl FromStr for Rgb {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match named(s, Rgb::NAMED) {
+            Some(c) => Ok(c),
+            None => parse(s).map(Rgb::from),
+        }
+    }
+}
+
+impl FromStr for Rgbw {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match named(s, Rgbw::NAMED) {
+            Some(c) => Ok(c),
+            None => parse(s),
+        }
+    }
+}
+
+impl fmt::Display for Rgb {
+    /// Format as `#rrggbb`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", hex(self.0), hex(self.1), hex(self.2))
+    }
+}
+
+impl fmt::Display for Rgbw {
+    /// Format as `#rrggbbww`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}{:02x}", hex(self.0), hex(self.1), hex(self.2), hex(self.3))
+    }
+}
+
+/// Round rather than truncate like `Interp::byte`, so formatting a parsed color gives back the same hex.
+fn hex(fr: f64) -> u8 {
+    (fr.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn named<C: Copy>(s: &str, names: &[(&str, C)]) -> Option<C> {
+    let s = s.trim();
+    names.iter().find(|(name, _)| name.eq_ignore_ascii_case(s)).map(|&(_, c)| c)
+}
+
+fn parse(s: &str) -> Result<Rgbw> {
+    let s = s.trim();
+    let lower = s.to_ascii_lowercase();
+
+    if let Some(hex) = lower.strip_prefix('#') {
+        return parse_hex(hex).with_context(|| format!("Invalid hex color {s:?}"));
+    }
+
+    if let Some((func, args)) = lower.strip_suffix(')').and_then(|l| l.split_once('(')) {
+        return parse_func(func.trim(), args).with_context(|| format!("Invalid color {s:?}"));
+    }
+
+    if let Some(gel) = Gel::find(s) {
+        return Ok(gel.rgbw());
+    }
+
+    match CSS.iter().find(|(name, _)| *name == lower) {
+        Some(&(_, v)) => Ok(Rgb(byte(v >> 16), byte(v >> 8), byte(v)).into()),
+        None => bail!("Unknown color {s:?}"),
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Rgbw> {
+    let digits = hex.chars().map(|c| c.to_digit(16)).collect::<Option<Vec<_>>>().context("Expected hex digits")?;
+    let digit = |i: usize| digits[i] as f64 / 15.0;
+    let pair = |i: usize| (digits[2 * i] * 16 + digits[2 * i + 1]) as f64 / 255.0;
+    match digits.len() {
+        3 => Ok(Rgbw(digit(0), digit(1), digit(2), 0.0)),
+        6 => Ok(Rgbw(pair(0), pair(1), pair(2), 0.0)),
+        8 => Ok(Rgbw(pair(0), pair(1), pair(2), pair(3))),
+        n => bail!("Expected 3, 6 or 8 digits, got {n}"),
+    }
+}
+
+fn parse_func(func: &str, args: &str) -> Result<Rgbw> {
+    let args: Vec<&str> = args.split([',', ' ']).map(str::trim).filter(|a| !a.is_empty()).collect();
+    match (func, &args[..]) {
+        ("rgb", &[r, g, b]) => Ok(Rgbw(channel(r)?, channel(g)?, channel(b)?, 0.0)),
+        ("rgbw", &[r, g, b, w]) => Ok(Rgbw(channel(r)?, channel(g)?, channel(b)?, channel(w)?)),
+        ("hsl", &[h, s, l]) => Ok(Rgb::from(Hsl(hue(h)?, percent(s)?, percent(l)?)).into()),
+        ("rgb" | "hsl", _) => bail!("Expected 3 arguments, got {}", args.len()),
+        ("rgbw", _) => bail!("Expected 4 arguments, got {}", args.len()),
+        _ => Err(anyhow!("Unknown function {func:?}")),
+    }
+}
+
+/// A channel as `0..255` or a percentage.
+fn channel(s: &str) -> Result<f64> {
+    match s.strip_suffix('%') {
+        Some(p) => Ok(number(p)? / 100.0),
+        None => Ok(number(s)? / 255.0),
+    }
+}
+
+/// A fraction as a percentage, or a plain number from `0..100`.
+fn percent(s: &str) -> Result<f64> {
+    Ok(number(s.strip_suffix('%').unwrap_or(s))? / 100.0)
+}
+
+/// A hue in degrees or turns, as turns.
+fn hue(s: &str) -> Result<f64> {
+    match s.strip_suffix("turn") {
+        Some(t) => number(t),
+        None => Ok(number(s.strip_suffix("deg").unwrap_or(s))? / 360.0),
+    }
+}
+
+fn number(s: &str) -> Result<f64> {
+    s.trim().parse().with_context(|| format!("Invalid number {s:?}"))
+}
+
+fn byte(v: u32) -> f64 {
+    (v & 0xff) as f64 / 255.0
+}
+
+/// CSS named colors.
+const CSS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
@@ -0,0 +1,136 @@
+//! Color schemes: harmonies around a base hue, and a random picker that doesn't repeat itself.
+
+use super::{Hsv, Palette, Rgb};
+use crate::num::Interp;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Hues in harmony with a base hue, as offsets in turns around the hue circle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Harmony {
+    /// The base and its opposite.
+    Complementary,
+    /// Three hues evenly spaced.
+    Triadic,
+    /// The base and its neighbors, `spread` turns to either side, e.g. `1.0 / 12.0`.
+    Analogous(f64),
+    /// The base and the two neighbors of its opposite, `spread` turns to either side.
+    SplitComplementary(f64),
+    /// Four hues evenly spaced.
+    Tetradic,
+}
+
+impl Harmony {
+    /// Hue offsets from the base, starting with the base itself.
+    pub fn offsets(self) -> Vec<f64> {
+        match self {
+            Harmony::Complementary => vec![0.0, 0.5],
+            Harmony::Triadic => vec![0.0, 1.0 / 3.0, 2.0 / 3.0],
+            Harmony::Analogous(spread) => vec![0.0, -spread, spread],
+            Harmony::SplitComplementary(spread) => vec![0.0, 0.5 - spread, 0.5 + spread],
+            Harmony::Tetradic => vec![0.0, 0.25, 0.5, 0.75],
+        }
+    }
+
+    /// Hues in turns, starting with `hue`.
+    pub fn hues(self, hue: f64) -> Vec<f64> {
+        self.offsets().into_iter().map(|o| (hue + o).fmod(1.0)).collect()
+    }
+
+    /// Colors with the same saturation and value as `base`, starting with `base`.
+    pub fn colors(self, base: Rgb) -> Vec<Rgb> {
+        let Hsv(h, s, v) = base.into();
+        self.hues(h).into_iter().map(|h| Rgb::hsv(h, s, v)).collect()
+    }
+
+    /// Colors as a palette, e.g. to pick from with a [`Picker`].
+    pub fn palette(self, base: Rgb) -> Palette {
+        Palette::new(format!("{self:?}"), self.colors(base))
+    }
+}
+
+/// Picks random colors with weights, never the same one twice in a row, even if it's listed twice.
+///
+/// Seed it for the same sequence every run, so a show plays back the same way.
+#[derive(Clone, Debug)]
+pub struct Picker {
+    colors: Vec<(Rgb, f64)>,
+    last: Option<usize>,
+    rng: StdRng,
+}
+
+impl Picker {
+    /// Pick evenly from colors.
+    pub fn new<I: IntoIterator<Item = Rgb>>(colors: I) -> Self {
+        Self::weighted(colors.into_iter().map(|c| (c, 1.0)))
+    }
+
+    /// Pick from `(color, weight)` pairs, with each picked in proportion to its weight.
+    ///
+    /// Negative, infinite and NaN weights count as 0.
+    pub fn weighted<I: IntoIterator<Item = (Rgb, f64)>>(colors: I) -> Self {
+        let weight = |w: f64| if w.is_finite() { w.max(0.0) } else { 0.0 };
+        Self {
+            colors: colors.into_iter().map(|(c, w)| (c, weight(w))).collect(),
+            last: None,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Restart from a seed, for a reproducible sequence.
+    pub fn seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+        self.last = None;
+    }
+
+    /// The most recently picked color. Not `last`, which [`Iterator::last`] would shadow.
+    pub fn previous(&self) -> Option<Rgb> {
+        self.last.map(|i| self.colors[i].0)
+    }
+
+    /// Pick the next color, or black if there are none.
+    pub fn pick(&mut self) -> Rgb {
+        let last = self.last.map(|i| self.colors[i].0);
+        let weight = |c: Rgb, w: f64| if Some(c) == last { 0.0 } else { w };
+        let total: f64 = self.colors.iter().map(|&(c, w)| weight(c, w)).sum();
+
+        // With only one color, or nothing else weighted, repeating is the only option.
+        let i = if total > 0.0 && total.is_finite() {
+            let mut x = self.rng.gen_range(0.0..total);
+            let mut pick = 0;
+            for (i, &(c, w)) in self.colors.iter().enumerate() {
+                let w = weight(c, w);
+                if w > 0.0 {
+                    pick = i;
+                    if x < w {
+                        break;
+                    }
+                    x -= w;
+                }
+            }
+            pick
+        } else {
+            match self.last {
+                Some(i) => i,
+                None if !self.colors.is_empty() => 0,
+                None => return Rgb::BLACK,
+            }
+        };
+
+        self.last = Some(i);
+        self.colors[i].0
+    }
+}
+
+impl From<&Palette> for Picker {
+    fn from(palette: &Palette) -> Self {
+        Self::new(palette.colors.iter().copied())
+    }
+}
+
+impl Iterator for Picker {
+    type Item = Rgb;
+    fn next(&mut self) -> Option<Rgb> {
+        Some(self.pick())
+    }
+}
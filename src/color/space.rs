@@ -0,0 +1,235 @@
+//! Cylindrical and perceptual color spaces, with conversions both ways to [`Rgb`] and [`Rgbw`].
+//!
+//! Hues are in turns, from `0.0..1.0`, to match [`Rgb::hsv`]. OkLab treats [`Rgb`] as linear sRGB,
+//! the same as [`Xyz`](super::Xyz), and conversions don't clamp, so colors outside the sRGB gamut
+//! come back out with channels outside `0.0..1.0`.
+
+use super::{Rgb, Rgbw};
+use crate::num::{Interp, TAU};
+
+/// Hue, saturation, value.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct Hsv(pub f64, pub f64, pub f64);
+
+/// Hue, saturation, lightness.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct Hsl(pub f64, pub f64, pub f64);
+
+/// Perceptual lightness `L` in `0.0..1.0` and opponent axes `a` (green-red) and `b` (blue-yellow).
+///
+/// https://bottosson.github.io/posts/oklab/
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct OkLab(pub f64, pub f64, pub f64);
+
+/// OkLab in polar form: lightness, chroma, hue.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct OkLch(pub f64, pub f64, pub f64);
+
+impl Hsv {
+    /// Interpolate towards `other`, taking the short way around the hue circle.
+    pub fn mix(self, other: Self, fr: f64) -> Self {
+        let (h0, h1) = (hue_of(self.0, self.1, other.0), hue_of(other.0, other.1, self.0));
+        Hsv(mix_hue(h0, h1, fr), fr.lerp(self.1..other.1), fr.lerp(self.2..other.2))
+    }
+}
+
+impl Hsl {
+    /// Interpolate towards `other`, taking the short way around the hue circle.
+    pub fn mix(self, other: Self, fr: f64) -> Self {
+        let (h0, h1) = (hue_of(self.0, self.1, other.0), hue_of(other.0, other.1, self.0));
+        Hsl(mix_hue(h0, h1, fr), fr.lerp(self.1..other.1), fr.lerp(self.2..other.2))
+    }
+}
+
+impl OkLab {
+    /// Interpolate towards `other` in a straight line.
+    pub fn mix(self, other: Self, fr: f64) -> Self {
+        OkLab(fr.lerp(self.0..other.0), fr.lerp(self.1..other.1), fr.lerp(self.2..other.2))
+    }
+}
+
+impl OkLch {
+    /// Interpolate towards `other`, taking the short way around the hue circle.
+    pub fn mix(self, other: Self, fr: f64) -> Self {
+        let (h0, h1) = (hue_of(self.2, self.1, other.2), hue_of(other.2, other.1, self.2));
+        OkLch(fr.lerp(self.0..other.0), fr.lerp(self.1..other.1), mix_hue(h0, h1, fr))
+    }
+}
+
+impl Rgb {
+    /// Crossfade towards `other` through OkLab, which keeps brightness even.
+    pub fn mix_oklab(self, other: Self, fr: f64) -> Self {
+        clip(OkLab::from(self).mix(other.into(), fr).into())
+    }
+
+    /// Crossfade towards `other` through OkLch, which also keeps saturation, so
+    /// complementary colors fade around the hue circle rather than through grey.
+    pub fn mix_oklch(self, other: Self, fr: f64) -> Self {
+        clip(OkLch::from(self).mix(other.into(), fr).into())
+    }
+}
+
+impl Rgbw {
+    /// Crossfade towards `other` through OkLab, see [`Rgb::mix_oklab`].
+    pub fn mix_oklab(self, other: Self, fr: f64) -> Self {
+        Rgb::from(self).mix_oklab(other.into(), fr).into()
+    }
+
+    /// Crossfade towards `other` through OkLch, see [`Rgb::mix_oklch`].
+    pub fn mix_oklch(self, other: Self, fr: f64) -> Self {
+        Rgb::from(self).mix_oklch(other.into(), fr).into()
+    }
+}
+
+/// Use the other color's hue for greys, which have none, so fades to and from grey don't shift hue.
+fn hue_of(hue: f64, chroma: f64, other: f64) -> f64 {
+    if chroma.abs() < 1e-6 {
+        other
+    } else {
+        hue
+    }
+}
+
+/// Interpolate between hues in turns, the short way around.
+fn mix_hue(h0: f64, h1: f64, fr: f64) -> f64 {
+    let d = (h1 - h0 + 0.5).fmod(1.0) - 0.5;
+    (h0 + d * fr.clamp(0.0, 1.0)).fmod(1.0)
+}
+
+/// Bring an out of gamut color back into `0.0..1.0`, keeping its hue.
+fn clip(Rgb(r, g, b): Rgb) -> Rgb {
+    let (r, g, b) = (r.max(0.0), g.max(0.0), b.max(0.0));
+    let max = r.max(g).max(b);
+    if max > 1.0 {
+        Rgb(r / max, g / max, b / max)
+    } else {
+        Rgb(r, g, b)
+    }
+}
+
+/// Conversions
+mod conv {
+    use super::*;
+
+    impl From<Rgb> for Hsv {
+        fn from(Rgb(r, g, b): Rgb) -> Self {
+            let max = r.max(g).max(b);
+            let min = r.min(g).min(b);
+            let sat = if max > 0.0 { (max - min) / max } else { 0.0 };
+            Hsv(hue(r, g, b, max, min), sat, max)
+        }
+    }
+
+    impl From<Hsv> for Rgb {
+        fn from(Hsv(h, s, v): Hsv) -> Self {
+            Rgb::hsv(h.fmod(1.0), s, v)
+        }
+    }
+
+    impl From<Rgb> for Hsl {
+        fn from(Rgb(r, g, b): Rgb) -> Self {
+            let max = r.max(g).max(b);
+            let min = r.min(g).min(b);
+            let l = (max + min) / 2.0;
+            let d = 1.0 - (2.0 * l - 1.0).abs();
+            let sat = if d > 0.0 { (max - min) / d } else { 0.0 };
+            Hsl(hue(r, g, b, max, min), sat, l)
+        }
+    }
+
+    impl From<Hsl> for Rgb {
+        fn from(Hsl(h, s, l): Hsl) -> Self {
+            let v = l + s * l.min(1.0 - l);
+            let sv = if v > 0.0 { 2.0 * (1.0 - l / v) } else { 0.0 };
+            Hsv(h, sv, v).into()
+        }
+    }
+
+    impl From<Rgb> for OkLab {
+        fn from(Rgb(r, g, b): Rgb) -> Self {
+            let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+            let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+            let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+            OkLab(
+                0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+                1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+                0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+            )
+        }
+    }
+
+    impl From<OkLab> for Rgb {
+        fn from(OkLab(l, a, b): OkLab) -> Self {
+            let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+            let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+            let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+            Rgb(
+                4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+                -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+                -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+            )
+        }
+    }
+
+    impl From<OkLab> for OkLch {
+        fn from(OkLab(l, a, b): OkLab) -> Self {
+            OkLch(l, a.hypot(b), (b.atan2(a) / TAU).fmod(1.0))
+        }
+    }
+
+    impl From<OkLch> for OkLab {
+        fn from(OkLch(l, c, h): OkLch) -> Self {
+            let (sin, cos) = (h * TAU).sin_cos();
+            OkLab(l, c * cos, c * sin)
+        }
+    }
+
+    impl From<Rgb> for OkLch {
+        fn from(rgb: Rgb) -> Self {
+            OkLab::from(rgb).into()
+        }
+    }
+
+    impl From<OkLch> for Rgb {
+        fn from(lch: OkLch) -> Self {
+            OkLab::from(lch).into()
+        }
+    }
+
+    /// Conversions to and from Rgbw, through Rgb.
+    macro_rules! via_rgb {
+        ($($ty:ty),*) => {
+            $(
+                impl From<Rgbw> for $ty {
+                    fn from(rgbw: Rgbw) -> Self {
+                        Rgb::from(rgbw).into()
+                    }
+                }
+
+                impl From<$ty> for Rgbw {
+                    fn from(c: $ty) -> Self {
+                        Rgb::from(c).into()
+                    }
+                }
+            )*
+        };
+    }
+
+    via_rgb!(Hsv, Hsl, OkLab, OkLch);
+
+    /// Hue in turns of an rgb color with the given max and min channels.
+    fn hue(r: f64, g: f64, b: f64, max: f64, min: f64) -> f64 {
+        let d = max - min;
+        if d <= 0.0 {
+            0.0
+        } else if max == r {
+            ((g - b) / d / 6.0).fmod(1.0)
+        } else if max == g {
+            ((b - r) / d + 2.0) / 6.0
+        } else {
+            ((r - g) / d + 4.0) / 6.0
+        }
+    }
+}
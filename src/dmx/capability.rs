@@ -0,0 +1,75 @@
+//! Capabilities shared across fixture drivers, so effects can target mixed groups of fixtures.
+//!
+//! Drivers implement whichever capabilities they have channels for, and may emulate
+//! ones they lack, e.g. a [`Par`](super::device::par_rgbw_12x3w::Par) in its 4 channel mode dims by
+//! scaling its color.
+
+use crate::color::Rgbw;
+
+/// Overall intensity, in 0..1.
+pub trait HasDimmer {
+    fn dimmer(&self) -> f64;
+    fn set_dimmer(&mut self, fr: f64);
+}
+
+/// Color, converted to and from the fixture's native color type.
+pub trait HasColor {
+    fn color(&self) -> Rgbw;
+    fn set_color(&mut self, color: Rgbw);
+}
+
+/// Pan and tilt, in 0..1 of the fixture's range of motion.
+pub trait HasPanTilt {
+    fn pan(&self) -> f64;
+    fn tilt(&self) -> f64;
+    fn set_pan(&mut self, fr: f64);
+    fn set_tilt(&mut self, fr: f64);
+}
+
+/// Strobe rate in 0..1, where 0 is off and 1 is the fastest.
+pub trait HasStrobe {
+    fn strobe(&self) -> f64;
+    fn set_strobe(&mut self, fr: f64);
+}
+
+/// Beam angle in 0..1, from narrowest to widest.
+pub trait HasZoom {
+    fn zoom(&self) -> f64;
+    fn set_zoom(&mut self, fr: f64);
+}
+
+/// Forward capabilities through boxes and references, so groups can hold e.g. `Box<dyn HasColor>`.
+macro_rules! impl_forward {
+    ($($trait:ident($ty:ty): $($get:ident / $set:ident),*;)*) => {
+        $(
+            impl<T: $trait + ?Sized> $trait for Box<T> {
+                $(
+                    fn $get(&self) -> $ty {
+                        (**self).$get()
+                    }
+                    fn $set(&mut self, v: $ty) {
+                        (**self).$set(v)
+                    }
+                )*
+            }
+            impl<T: $trait + ?Sized> $trait for &mut T {
+                $(
+                    fn $get(&self) -> $ty {
+                        (**self).$get()
+                    }
+                    fn $set(&mut self, v: $ty) {
+                        (**self).$set(v)
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+impl_forward! {
+    HasDimmer(f64): dimmer / set_dimmer;
+    HasColor(Rgbw): color / set_color;
+    HasPanTilt(f64): pan / set_pan, tilt / set_tilt;
+    HasStrobe(f64): strobe / set_strobe;
+    HasZoom(f64): zoom / set_zoom;
+}
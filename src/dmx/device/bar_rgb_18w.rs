@@ -2,59 +2,163 @@
 //!
 //! https://www.amazon.com/gp/product/B0045EP4WG
 
-use crate::color::Rgbw;
-use crate::dmx::Device;
+use crate::color::{Emitters, Rgb, Rgbw};
+use crate::dmx::device::write_levels;
+use crate::dmx::{Device, HasColor, HasDimmer, HasStrobe, Profile};
 use crate::num::Interp;
 
 #[derive(Default, Clone, Copy, Debug)]
 pub struct Bar {
+    pub personality: BarPersonality,
+    pub mode: BarMode,
+    pub preset: BarPreset,
+    pub strobe: f64,
+
     pub color: Rgbw,
     pub alpha: f64,
+    pub profile: Profile,
+}
+
+/// DMX channel modes, as set in the fixture's menu.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BarPersonality {
+    #[default]
+    Ch7,
+    /// RGB only, with `alpha` applied to the color.
+    Ch3,
+}
+
+/// Built-in programs.
+#[derive(Default, Clone, Copy, Debug)]
+pub enum BarMode {
+    #[default]
+    Manual,
+    ColorCycle,
+    Auto,
+    Raw(u8),
+}
+
+/// Built-in preset colors, which override `color` when set.
+#[derive(Default, Clone, Copy, Debug)]
+pub enum BarPreset {
+    #[default]
+    Off,
+
+    Red,
+    Green,
+    Blue,
+    Yellow,
+    Purple,
+    Teal,
+    White,
+
+    Raw(u8),
+}
+
+impl BarPersonality {
+    pub fn channels(self) -> usize {
+        match self {
+            BarPersonality::Ch7 => 7,
+            BarPersonality::Ch3 => 3,
+        }
+    }
+}
+
+impl Bar {
+    pub const EMITTERS: Emitters = Emitters::RGB;
+
+    pub fn new(personality: BarPersonality) -> Self {
+        Self { personality, ..Default::default() }
+    }
 }
 
 impl Device for Bar {
     fn channels(&self) -> usize {
-        7
+        self.personality.channels()
     }
 
     fn encode(&self, buf: &mut [u8]) {
-        let Rgbw(r, g, b, w) = self.color;
-
-        if r == g && g == b && w == 0.0 {
-            // ignore Rgb::WHITE since we have to white channel
-            buf[0] = 0;
-            buf[1] = 0;
-            buf[2] = 0;
-        } else {
-            buf[0] = r.byte();
-            buf[1] = g.byte();
-            buf[2] = b.byte();
+        let mut levels = [None; 7];
+        self.levels(&mut levels);
+        write_levels(buf, &levels);
+
+        if self.personality == BarPersonality::Ch7 {
+            buf[3] = self.preset.byte();
+            buf[4] = self.strobe.byte();
+            buf[5] = self.mode.byte();
+        }
+    }
+
+    fn levels(&self, levels: &mut [Option<f64>]) {
+        let [r, g, b, ..] = self.profile.map(Self::EMITTERS, self.color);
+        let color = match self.personality {
+            BarPersonality::Ch7 => Rgb(r, g, b),
+            BarPersonality::Ch3 => Rgb(r, g, b) * self.alpha,
+        };
+        let Rgb(r, g, b) = self.profile.rgb(color);
+
+        levels[0] = Some(r);
+        levels[1] = Some(g);
+        levels[2] = Some(b);
+
+        if self.personality == BarPersonality::Ch7 {
+            levels[6] = Some(self.profile.dimmer(self.alpha));
+        }
+    }
+}
+
+impl HasDimmer for Bar {
+    fn dimmer(&self) -> f64 {
+        self.alpha
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.alpha = fr;
+    }
+}
+
+impl HasColor for Bar {
+    fn color(&self) -> Rgbw {
+        self.color
+    }
+    fn set_color(&mut self, color: Rgbw) {
+        self.color = color;
+    }
+}
+
+impl HasStrobe for Bar {
+    fn strobe(&self) -> f64 {
+        self.strobe
+    }
+    fn set_strobe(&mut self, fr: f64) {
+        self.strobe = fr;
+    }
+}
+
+impl BarMode {
+    pub fn byte(&self) -> u8 {
+        match self {
+            BarMode::Manual => 0,
+            BarMode::ColorCycle => 159,
+            BarMode::Auto => 60,
+            BarMode::Raw(i) => *i,
         }
+    }
+}
+
+impl BarPreset {
+    pub fn byte(&self) -> u8 {
+        match self {
+            BarPreset::Off => 0,
+
+            BarPreset::Red => 16,
+            BarPreset::Green => 32,
+            BarPreset::Blue => 48,
+            BarPreset::Yellow => 64,
+            BarPreset::Purple => 80,
+            BarPreset::Teal => 96,
+            BarPreset::White => 112,
 
-        buf[0] = r.byte();
-        buf[1] = g.byte();
-        buf[2] = b.byte();
-        // buf[3]: preset colors
-        // buf[4]: strobe
-        // buf[5]: mode
-        buf[6] = self.alpha.byte();
-    }
-}
-
-// #[derive(Default, Clone, Copy, Debug)]
-// pub enum BarMode {
-//     #[default]
-//     Manual,
-//     ColorCycle,
-//     Auto,
-// }
-
-// impl BarMode {
-//     pub fn byte(&self) -> u8 {
-//         match self {
-//             BarMode::Manual => 0,
-//             BarMode::ColorCycle => 159,
-//             BarMode::Auto => 60,
-//         }
-//     }
-// }
+            BarPreset::Raw(i) => *i,
+        }
+    }
+}
@@ -2,14 +2,18 @@
 //!
 //! TODO: amazon link
 
-use crate::color::Rgbw;
-use crate::dmx::Device;
+use crate::color::{Emitters, Rgbw};
+use crate::dmx::{Device, HasColor, HasDimmer, HasPanTilt, HasStrobe, Profile};
 use crate::num::Interp;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Beam {
+    pub personality: BeamPersonality,
+
     pub mode: BeamMode,
     pub ring: BeamRing,
+    pub preset: BeamPreset,
+    pub motion: BeamMotion,
 
     pub pitch: f64,
     pub yaw: f64,
@@ -17,6 +21,26 @@ pub struct Beam {
 
     pub color: Rgbw,
     pub alpha: f64,
+    pub strobe: f64,
+    pub profile: Profile,
+}
+
+/// DMX channel modes, as set in the fixture's menu.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BeamPersonality {
+    #[default]
+    Ch15,
+    /// Without the fine pan/tilt channels.
+    Ch13,
+}
+
+impl BeamPersonality {
+    pub fn channels(self) -> usize {
+        match self {
+            BeamPersonality::Ch15 => 15,
+            BeamPersonality::Ch13 => 13,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -26,6 +50,35 @@ pub enum BeamMode {
     Auto,
 }
 
+/// Built-in preset colors for the main beam, which override `color` when set.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum BeamPreset {
+    #[default]
+    Off,
+
+    Red,
+    Green,
+    Blue,
+    White,
+    Yellow,
+    Purple,
+    Teal,
+
+    Cycle,
+    Raw(u8),
+}
+
+/// Automatic pan/tilt programs, and resetting the motors.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum BeamMotion {
+    #[default]
+    Manual,
+    Auto,
+    Sound,
+    Reset,
+    Raw(u8),
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub enum BeamRing {
     #[default]
@@ -55,31 +108,125 @@ pub enum BeamRing {
     Raw(u8),
 }
 
+impl Beam {
+    pub const EMITTERS: Emitters = Emitters::RGBW;
+
+    pub fn new(personality: BeamPersonality) -> Self {
+        Self { personality, ..Default::default() }
+    }
+}
+
 impl Device for Beam {
     fn channels(&self) -> usize {
-        15
+        self.personality.channels()
     }
 
     fn encode(&self, buf: &mut [u8]) {
-        let Rgbw(r, g, b, w) = self.color;
-
-        buf[0] = self.yaw.byte();
-        // buf[0] = (self.yaw * (2.0 / 3.0)).byte();
-        // buf[0] = self.yaw.lerp((1.0 / 3.0)..1.0).byte();
-        // buf[1]: yaw fine
-        buf[2] = self.pitch.byte();
-        // buf[3]: pitch fine
-        buf[4] = (1.0 - self.speed).byte();
-        buf[5] = self.alpha.byte();
-        // buf[6]: strobe
-        buf[7] = r.byte();
-        buf[8] = g.byte();
-        buf[9] = b.byte();
-        buf[10] = w.byte();
-        // buf[11]: color preset
-        buf[12] = self.mode.byte();
-        // buf[13]: auto pitch/yaw, reset
-        buf[14] = self.ring.byte();
+        let [r, g, b, w, ..] = self.profile.map(Self::EMITTERS, self.color);
+        let Rgbw(r, g, b, w) = self.profile.color(Rgbw(r, g, b, w));
+
+        // The rest of the channels are the same in both modes, just shifted back without fine pan/tilt.
+        let buf = match self.personality {
+            BeamPersonality::Ch15 => {
+                buf[0] = self.yaw.byte();
+                // buf[0] = (self.yaw * (2.0 / 3.0)).byte();
+                // buf[0] = self.yaw.lerp((1.0 / 3.0)..1.0).byte();
+                // buf[1]: yaw fine
+                buf[2] = self.pitch.byte();
+                // buf[3]: pitch fine
+                &mut buf[4..]
+            }
+            BeamPersonality::Ch13 => {
+                buf[0] = self.yaw.byte();
+                buf[1] = self.pitch.byte();
+                &mut buf[2..]
+            }
+        };
+
+        buf[0] = (1.0 - self.speed).byte();
+        buf[1] = self.profile.dimmer(self.alpha).byte();
+        buf[2] = self.strobe.byte();
+        buf[3] = r.byte();
+        buf[4] = g.byte();
+        buf[5] = b.byte();
+        buf[6] = w.byte();
+        buf[7] = self.preset.byte();
+        buf[8] = self.mode.byte();
+        buf[9] = self.motion.byte();
+        buf[10] = self.ring.byte();
+    }
+}
+
+impl HasDimmer for Beam {
+    fn dimmer(&self) -> f64 {
+        self.alpha
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.alpha = fr;
+    }
+}
+
+impl HasColor for Beam {
+    fn color(&self) -> Rgbw {
+        self.color
+    }
+    fn set_color(&mut self, color: Rgbw) {
+        self.color = color;
+    }
+}
+
+impl HasPanTilt for Beam {
+    fn pan(&self) -> f64 {
+        self.yaw
+    }
+    fn tilt(&self) -> f64 {
+        self.pitch
+    }
+    fn set_pan(&mut self, fr: f64) {
+        self.yaw = fr;
+    }
+    fn set_tilt(&mut self, fr: f64) {
+        self.pitch = fr;
+    }
+}
+
+impl HasStrobe for Beam {
+    fn strobe(&self) -> f64 {
+        self.strobe
+    }
+    fn set_strobe(&mut self, fr: f64) {
+        self.strobe = fr;
+    }
+}
+
+impl BeamPreset {
+    pub fn byte(&self) -> u8 {
+        match self {
+            BeamPreset::Off => 0,
+
+            BeamPreset::Red => 10,
+            BeamPreset::Green => 20,
+            BeamPreset::Blue => 30,
+            BeamPreset::White => 40,
+            BeamPreset::Yellow => 50,
+            BeamPreset::Purple => 60,
+            BeamPreset::Teal => 70,
+
+            BeamPreset::Cycle => 200,
+            BeamPreset::Raw(i) => *i,
+        }
+    }
+}
+
+impl BeamMotion {
+    pub fn byte(&self) -> u8 {
+        match self {
+            BeamMotion::Manual => 0,
+            BeamMotion::Auto => 60,
+            BeamMotion::Sound => 160,
+            BeamMotion::Reset => 255,
+            BeamMotion::Raw(i) => *i,
+        }
     }
 }
 
@@ -127,8 +274,12 @@ impl BeamMode {
 impl Default for Beam {
     fn default() -> Self {
         Self {
+            personality: BeamPersonality::Ch15,
+
             mode: BeamMode::Manual,
             ring: BeamRing::Off,
+            preset: BeamPreset::Off,
+            motion: BeamMotion::Manual,
 
             pitch: 0.0,
             yaw: 0.33,
@@ -136,6 +287,8 @@ impl Default for Beam {
 
             color: Rgbw::BLACK,
             alpha: 1.0,
+            strobe: 0.0,
+            profile: Profile::default(),
         }
     }
 }
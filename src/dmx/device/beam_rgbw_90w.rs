@@ -3,34 +3,68 @@
 //! https://www.amazon.com/gp/product/B089QGPJ2L
 //! https://www.aliexpress.com/w/wholesale-Beam-60W-LED-Moving-Head-RGBW-4-IN-1-Stage-Lightin.html
 
-use crate::color::Rgbw;
-use crate::dmx::Device;
+use crate::color::{Emitters, Rgbw};
+use crate::dmx::{Device, HasColor, HasDimmer, HasPanTilt, HasStrobe, Profile};
 use crate::num::Interp;
 
 #[derive(Clone, Copy, Debug)]
 pub struct BigBeam {
+    pub personality: BigBeamPersonality,
+
     pub pitch: f64,
     pub yaw: f64,
     pub speed: f64,
     pub color: Rgbw,
     pub alpha: f64,
     pub strobe: f64,
+    pub profile: Profile,
+}
+
+/// DMX channel modes, as set in the fixture's menu.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BigBeamPersonality {
+    /// Pan, tilt, speed, dimmer, strobe, red, green, blue and white, then four channels for
+    /// built-in programs and reset, which are left at 0.
+    #[default]
+    Ch13,
+    /// Pan, tilt, speed, dimmer, strobe, red, green, blue and white, without the program and reset
+    /// channels.
+    Ch9,
+}
+
+impl BigBeamPersonality {
+    pub fn channels(self) -> usize {
+        match self {
+            BigBeamPersonality::Ch13 => 13,
+            BigBeamPersonality::Ch9 => 9,
+        }
+    }
+}
+
+impl BigBeam {
+    pub const EMITTERS: Emitters = Emitters::RGBW;
+
+    pub fn new(personality: BigBeamPersonality) -> Self {
+        Self { personality, ..Default::default() }
+    }
 }
 
 impl Device for BigBeam {
     fn channels(&self) -> usize {
-        13
+        self.personality.channels()
     }
 
     fn encode(&self, buf: &mut [u8]) {
-        let Rgbw(r, g, b, w) = self.color;
+        let [r, g, b, w, ..] = self.profile.map(Self::EMITTERS, self.color);
+        let Rgbw(r, g, b, w) = self.profile.color(Rgbw(r, g, b, w));
 
+        // Both modes start with the same 9 channels.
         buf[0] = self.yaw.byte();
         // buf[0] = (self.yaw * (2.0 / 3.0)).byte();
         // buf[0] = self.yaw.lerp((1.0 / 3.0)..1.0).byte();
         buf[1] = self.pitch.inv().byte();
         buf[2] = (1.0 - self.speed).byte();
-        buf[3] = self.alpha.byte();
+        buf[3] = self.profile.dimmer(self.alpha).byte();
         buf[4] = self.strobe.byte();
         buf[5] = r.byte();
         buf[6] = g.byte();
@@ -39,9 +73,53 @@ impl Device for BigBeam {
     }
 }
 
+impl HasDimmer for BigBeam {
+    fn dimmer(&self) -> f64 {
+        self.alpha
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.alpha = fr;
+    }
+}
+
+impl HasColor for BigBeam {
+    fn color(&self) -> Rgbw {
+        self.color
+    }
+    fn set_color(&mut self, color: Rgbw) {
+        self.color = color;
+    }
+}
+
+impl HasPanTilt for BigBeam {
+    fn pan(&self) -> f64 {
+        self.yaw
+    }
+    fn tilt(&self) -> f64 {
+        self.pitch
+    }
+    fn set_pan(&mut self, fr: f64) {
+        self.yaw = fr;
+    }
+    fn set_tilt(&mut self, fr: f64) {
+        self.pitch = fr;
+    }
+}
+
+impl HasStrobe for BigBeam {
+    fn strobe(&self) -> f64 {
+        self.strobe
+    }
+    fn set_strobe(&mut self, fr: f64) {
+        self.strobe = fr;
+    }
+}
+
 impl Default for BigBeam {
     fn default() -> Self {
         Self {
+            personality: BigBeamPersonality::Ch13,
+
             pitch: 0.0,
             yaw: 0.33,
             speed: 1.0,
@@ -49,6 +127,7 @@ impl Default for BigBeam {
 
             color: Rgbw::BLACK,
             alpha: 1.0,
+            profile: Profile::default(),
         }
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! <TODO: amazon link>
 
-use crate::dmx::Device;
+use crate::dmx::{Device, HasDimmer, HasPanTilt, HasStrobe, Profile};
 use crate::num::Interp;
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -15,6 +15,7 @@ pub struct Gobo {
     pub alpha: f64,
     pub speed: f64,
     pub auto: f64,
+    pub profile: Profile,
 }
 
 impl Device for Gobo {
@@ -28,9 +29,42 @@ impl Device for Gobo {
         buf[2] = self.color.byte();
         buf[3] = self.pattern.byte();
         buf[4] = self.strobe.byte();
-        buf[5] = self.alpha.byte();
+        buf[5] = self.profile.dimmer(self.alpha).byte();
         buf[6] = self.speed.byte();
         buf[7] = self.auto.byte();
         buf[8] = 0; // reset
     }
 }
+
+impl HasDimmer for Gobo {
+    fn dimmer(&self) -> f64 {
+        self.alpha
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.alpha = fr;
+    }
+}
+
+impl HasPanTilt for Gobo {
+    fn pan(&self) -> f64 {
+        self.pan
+    }
+    fn tilt(&self) -> f64 {
+        self.tilt
+    }
+    fn set_pan(&mut self, fr: f64) {
+        self.pan = fr;
+    }
+    fn set_tilt(&mut self, fr: f64) {
+        self.tilt = fr;
+    }
+}
+
+impl HasStrobe for Gobo {
+    fn strobe(&self) -> f64 {
+        self.strobe
+    }
+    fn set_strobe(&mut self, fr: f64) {
+        self.strobe = fr;
+    }
+}
@@ -2,8 +2,8 @@
 //!
 //! https://www.amazon.com/gp/product/B09LVGQ2GY
 
-use crate::color::Rgb;
-use crate::dmx::Device;
+use crate::color::{Rgb, Rgbw};
+use crate::dmx::{Device, HasColor, HasDimmer};
 use crate::num::Interp;
 
 #[derive(Clone, Copy, Debug)]
@@ -97,6 +97,8 @@ impl LaserColor {
     pub const GREEN: Self = LaserColor::Rgb(false, true, false);
     pub const BLUE: Self = LaserColor::Rgb(false, false, true);
     pub const RGB: Self = LaserColor::Rgb(true, true, true);
+    /// No diodes, which turns the laser off.
+    pub const BLACK: Self = LaserColor::Rgb(false, false, false);
 
     pub fn byte(self) -> u8 {
         match self {
@@ -112,7 +114,7 @@ impl LaserColor {
                 (false, true, true) => 104, // BG
 
                 (true, true, true) => 64,   // RGB
-                (false, false, false) => 0, // unused
+                (false, false, false) => 0, // off, see encode
             },
 
             LaserColor::Mix(i) => match i % 7 {
@@ -128,13 +130,21 @@ impl LaserColor {
         }
     }
 
-    pub fn from_rgb(rgb: Rgb) -> Self {
-        match rgb {
-            Rgb::RED => Self::RED,
-            Rgb::LIME => Self::GREEN,
-            Rgb::BLUE => Self::BLUE,
-            _ => Self::Rgb(true, true, true),
+    /// Approximate as an Rgb color.
+    pub fn rgb(self) -> Rgb {
+        match self {
+            LaserColor::Rgb(r, g, b) => Rgb(r as u8 as f64, g as u8 as f64, b as u8 as f64),
+            LaserColor::Raw(_) | LaserColor::Mix(_) => Rgb::WHITE,
+        }
+    }
+
+    /// Nearest on/off mix of the laser's diodes, turning on each channel that's at least half the brightest.
+    pub fn from_rgb(Rgb(r, g, b): Rgb) -> Self {
+        let max = r.max(g).max(b);
+        if max <= 0.0 {
+            return Self::BLACK;
         }
+        Self::Rgb(r >= max * 0.5, g >= max * 0.5, b >= max * 0.5)
     }
 }
 
@@ -230,7 +240,8 @@ impl Device for Laser {
     }
 
     fn encode(&self, buf: &mut [u8]) {
-        buf[0] = if self.on { 64 } else { 0 };
+        let black = matches!(self.color, LaserColor::Rgb(false, false, false));
+        buf[0] = if self.on && !black { 64 } else { 0 };
         buf[1] = self.pattern.byte();
         buf[2] = self.rotate.lerp(0..127) as u8;
         buf[3] = self.yflip.lerp(0..127) as u8;
@@ -242,3 +253,26 @@ impl Device for Laser {
         buf[9] = self.stroke.byte();
     }
 }
+
+/// No dimmer channel, so the laser is on above half.
+impl HasDimmer for Laser {
+    fn dimmer(&self) -> f64 {
+        if self.on {
+            1.0
+        } else {
+            0.0
+        }
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.on = fr >= 0.5;
+    }
+}
+
+impl HasColor for Laser {
+    fn color(&self) -> Rgbw {
+        self.color.rgb().into()
+    }
+    fn set_color(&mut self, color: Rgbw) {
+        self.color = LaserColor::from_rgb(color.into());
+    }
+}
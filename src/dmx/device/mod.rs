@@ -7,7 +7,48 @@ pub mod par_rgbw_12x3w;
 pub mod spider_rgbw_8x10w;
 pub mod strobe_rgb_35w;
 
+use crate::num::Interp;
+
 pub trait Device {
     fn channels(&self) -> usize;
     fn encode(&self, buf: &mut [u8]);
+
+    /// Write the full-precision 0..1 levels of continuous channels like dimmers and colors,
+    /// before they're quantized to bytes in `encode`. Used by output stages like [`Dither`](super::dither::Dither).
+    ///
+    /// Channels left as `None` are output as-is from `encode`.
+    fn levels(&self, _levels: &mut [Option<f64>]) {}
+}
+
+/// Quantize the levels from [`Device::levels`] into `buf`, skipping channels without one.
+pub(crate) fn write_levels(buf: &mut [u8], levels: &[Option<f64>]) {
+    for (b, level) in buf.iter_mut().zip(levels) {
+        if let Some(level) = level {
+            *b = level.byte();
+        }
+    }
+}
+
+impl<T: Device + ?Sized> Device for Box<T> {
+    fn channels(&self) -> usize {
+        (**self).channels()
+    }
+    fn encode(&self, buf: &mut [u8]) {
+        (**self).encode(buf)
+    }
+    fn levels(&self, levels: &mut [Option<f64>]) {
+        (**self).levels(levels)
+    }
+}
+
+impl<T: Device + ?Sized> Device for &mut T {
+    fn channels(&self) -> usize {
+        (**self).channels()
+    }
+    fn encode(&self, buf: &mut [u8]) {
+        (**self).encode(buf)
+    }
+    fn levels(&self, levels: &mut [Option<f64>]) {
+        (**self).levels(levels)
+    }
 }
@@ -2,36 +2,148 @@
 //!
 //! https://www.aliexpress.com/w/wholesale-12x3w-rgbw-dmx-led-par-light.html
 
-use crate::color::Rgbw;
-use crate::dmx::Device;
+use crate::color::{Emitters, Rgbw};
+use crate::dmx::device::write_levels;
+use crate::dmx::{Device, HasColor, HasDimmer, HasStrobe, Profile};
 use crate::num::Interp;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Par {
+    pub personality: ParPersonality,
+
+    pub mode: ParMode,
+    pub speed: f64,
+    pub strobe: f64,
+
     pub color: Rgbw,
+    pub alpha: f64,
+    pub profile: Profile,
+}
+
+/// DMX channel modes, as set in the fixture's menu.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParPersonality {
+    #[default]
+    Ch8,
+    /// RGBW only, dimmed by scaling the color.
+    Ch4,
+}
+
+/// Built-in programs.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum ParMode {
+    #[default]
+    Manual,
+    ColorJump,
+    ColorFade,
+    Sound,
+    Raw(u8),
+}
+
+impl ParPersonality {
+    pub fn channels(self) -> usize {
+        match self {
+            ParPersonality::Ch8 => 8,
+            ParPersonality::Ch4 => 4,
+        }
+    }
+}
+
+impl ParMode {
+    pub fn byte(&self) -> u8 {
+        match self {
+            ParMode::Manual => 0,
+            ParMode::ColorJump => 51,
+            ParMode::ColorFade => 101,
+            ParMode::Sound => 201,
+            ParMode::Raw(i) => *i,
+        }
+    }
+}
+
+impl Par {
+    pub const EMITTERS: Emitters = Emitters::RGBW;
+
+    pub fn new(personality: ParPersonality) -> Self {
+        Self { personality, ..Default::default() }
+    }
 }
 
 impl Device for Par {
     fn channels(&self) -> usize {
-        8
+        self.personality.channels()
     }
 
     fn encode(&self, buf: &mut [u8]) {
-        let Rgbw(r, g, b, w) = self.color;
+        let mut levels = [None; 8];
+        self.levels(&mut levels);
+        write_levels(buf, &levels);
 
-        // buf[0]: e
-        // buf[1]: ?
-        // buf[2]: ?
-        buf[3] = 1.0.byte();
-        buf[4] = r.byte();
-        buf[5] = g.byte();
-        buf[6] = b.byte();
-        buf[7] = w.byte();
+        if self.personality == ParPersonality::Ch8 {
+            buf[0] = self.mode.byte();
+            buf[1] = self.speed.byte();
+            buf[2] = self.strobe.byte();
+        }
+    }
+
+    fn levels(&self, levels: &mut [Option<f64>]) {
+        let [r, g, b, w, ..] = self.profile.map(Self::EMITTERS, self.color);
+        let color = Rgbw(r, g, b, w);
+        let (levels, color) = match self.personality {
+            ParPersonality::Ch8 => {
+                levels[3] = Some(self.profile.dimmer(self.alpha));
+                (&mut levels[4..], color)
+            }
+            ParPersonality::Ch4 => (levels, color * self.alpha),
+        };
+        let Rgbw(r, g, b, w) = self.profile.color(color);
+
+        levels[0] = Some(r);
+        levels[1] = Some(g);
+        levels[2] = Some(b);
+        levels[3] = Some(w);
     }
 }
 
 impl Default for Par {
     fn default() -> Self {
-        Self { color: Rgbw::BLACK }
+        Self {
+            personality: ParPersonality::Ch8,
+
+            mode: ParMode::Manual,
+            speed: 0.0,
+            strobe: 0.0,
+
+            color: Rgbw::BLACK,
+            alpha: 1.0,
+            profile: Profile::default(),
+        }
+    }
+}
+
+impl HasDimmer for Par {
+    fn dimmer(&self) -> f64 {
+        self.alpha
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.alpha = fr;
+    }
+}
+
+impl HasColor for Par {
+    fn color(&self) -> Rgbw {
+        self.color
+    }
+    fn set_color(&mut self, color: Rgbw) {
+        self.color = color;
+    }
+}
+
+impl HasStrobe for Par {
+    fn strobe(&self) -> f64 {
+        self.strobe
+    }
+    fn set_strobe(&mut self, fr: f64) {
+        self.strobe = fr;
     }
 }
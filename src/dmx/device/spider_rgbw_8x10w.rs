@@ -2,15 +2,20 @@
 //!
 //! https://www.amazon.com/gp/product/B081H833BG
 
-use crate::color::Rgbw;
-use crate::dmx::Device;
+use crate::color::{Emitters, Rgbw};
+use crate::dmx::{Device, HasColor, HasDimmer, HasStrobe, Profile};
 use crate::num::Interp;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Spider {
-    // pub mode: SpiderMode,
-    // pub speed: f64,
+    pub personality: SpiderPersonality,
+    pub mode: SpiderMode,
+    pub speed: f64,
+    pub reset: bool,
+
     pub alpha: f64,
+    pub strobe: f64,
+    pub profile: Profile,
 
     pub color0: Rgbw,
     pub pos0: f64,
@@ -19,57 +24,131 @@ pub struct Spider {
     pub pos1: f64,
 }
 
+/// DMX channel modes, as set in the fixture's menu.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpiderPersonality {
+    #[default]
+    Ch15,
+    /// Both sides share `color0`.
+    Ch9,
+}
+
+/// Built-in effect presets.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum SpiderMode {
+    #[default]
+    Manual,
+    ColorCycle,
+    Auto,
+    Raw(u8),
+}
+
+impl SpiderPersonality {
+    pub fn channels(self) -> usize {
+        match self {
+            SpiderPersonality::Ch15 => 15,
+            SpiderPersonality::Ch9 => 9,
+        }
+    }
+}
+
+impl Spider {
+    pub const EMITTERS: Emitters = Emitters::RGBW;
+
+    pub fn new(personality: SpiderPersonality) -> Self {
+        Self { personality, ..Default::default() }
+    }
+}
+
 impl Device for Spider {
     fn channels(&self) -> usize {
-        15
+        self.personality.channels()
     }
 
     fn encode(&self, buf: &mut [u8]) {
-        let Rgbw(r0, g0, b0, w0) = self.color0;
-        let Rgbw(r1, g1, b1, w1) = self.color1;
+        let [r0, g0, b0, w0, ..] = self.profile.map(Self::EMITTERS, self.color0);
+        let [r1, g1, b1, w1, ..] = self.profile.map(Self::EMITTERS, self.color1);
+        let Rgbw(r0, g0, b0, w0) = self.profile.color(Rgbw(r0, g0, b0, w0));
+        let Rgbw(r1, g1, b1, w1) = self.profile.color(Rgbw(r1, g1, b1, w1));
 
         buf[0] = self.pos0.byte();
         buf[1] = self.pos1.byte();
-        buf[2] = self.alpha.byte();
-        // buf[3]: strobe
+        buf[2] = self.profile.dimmer(self.alpha).byte();
+        buf[3] = self.strobe.byte();
 
         buf[4] = r0.byte();
         buf[5] = g0.byte();
         buf[6] = b0.byte();
         buf[7] = w0.byte();
 
-        buf[8] = r1.byte();
-        buf[9] = g1.byte();
-        buf[10] = b1.byte();
-        buf[11] = w1.byte();
-        // buf[12]: effect preset
-        // buf[13]: effect speed
-        // buf[14]: reset
+        match self.personality {
+            SpiderPersonality::Ch15 => {
+                buf[8] = r1.byte();
+                buf[9] = g1.byte();
+                buf[10] = b1.byte();
+                buf[11] = w1.byte();
+                buf[12] = self.mode.byte();
+                buf[13] = self.speed.byte();
+                buf[14] = if self.reset { 255 } else { 0 };
+            }
+            SpiderPersonality::Ch9 => {
+                buf[8] = self.mode.byte();
+            }
+        }
+    }
+}
+
+impl HasDimmer for Spider {
+    fn dimmer(&self) -> f64 {
+        self.alpha
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.alpha = fr;
     }
 }
 
-// pub enum SpiderMode {
-//     Manual,
-//     ColorCycle,
-//     Auto,
-// }
-
-// impl SpiderMode {
-//     pub fn byte(&self) -> u8 {
-//         match self {
-//             SpiderMode::Manual => 0,
-//             SpiderMode::ColorCycle => 159,
-//             SpiderMode::Auto => 60,
-//         }
-//     }
-// }
+/// Sets both sides, and reads back `color0`.
+impl HasColor for Spider {
+    fn color(&self) -> Rgbw {
+        self.color0
+    }
+    fn set_color(&mut self, color: Rgbw) {
+        self.color0 = color;
+        self.color1 = color;
+    }
+}
+
+impl HasStrobe for Spider {
+    fn strobe(&self) -> f64 {
+        self.strobe
+    }
+    fn set_strobe(&mut self, fr: f64) {
+        self.strobe = fr;
+    }
+}
+
+impl SpiderMode {
+    pub fn byte(&self) -> u8 {
+        match self {
+            SpiderMode::Manual => 0,
+            SpiderMode::ColorCycle => 159,
+            SpiderMode::Auto => 60,
+            SpiderMode::Raw(i) => *i,
+        }
+    }
+}
 
 impl Default for Spider {
     fn default() -> Self {
         Self {
-            // mode: SpiderMode::Manual,
-            // speed: 1.0,
+            personality: SpiderPersonality::Ch15,
+            mode: SpiderMode::Manual,
+            speed: 1.0,
+            reset: false,
+
             alpha: 1.0,
+            strobe: 0.0,
+            profile: Profile::default(),
 
             color0: Rgbw::BLACK,
             pos0: 0.0,
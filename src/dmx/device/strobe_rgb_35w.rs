@@ -2,15 +2,32 @@
 //!
 //! https://www.amazon.com/gp/product/B01MZYQJSA
 
-use crate::color::Rgb;
-use crate::dmx::Device;
+use crate::color::{Emitters, Rgb, Rgbw};
+use crate::dmx::{Device, HasColor, HasDimmer, Profile};
 use crate::num::Interp;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Strobe {
-    // pub mode: StrobeMode,
+    pub mode: StrobeMode,
+    /// Microphone sensitivity for the sound-active mode.
+    pub sound: f64,
     pub color: Rgb,
     pub alpha: f64,
+    pub profile: Profile,
+}
+
+/// Built-in programs.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum StrobeMode {
+    #[default]
+    Manual,
+    ColorCycle,
+    Auto,
+    Raw(u8),
+}
+
+impl Strobe {
+    pub const EMITTERS: Emitters = Emitters::RGB;
 }
 
 impl Device for Strobe {
@@ -19,39 +36,55 @@ impl Device for Strobe {
     }
 
     fn encode(&self, buf: &mut [u8]) {
-        let Rgb(r, g, b) = self.color;
+        let [r, g, b, ..] = self.profile.map(Self::EMITTERS, self.color.into());
+        let Rgb(r, g, b) = self.profile.rgb(Rgb(r, g, b));
 
-        buf[0] = self.alpha.byte();
-        // buf[1]: mode
+        buf[0] = self.profile.dimmer(self.alpha).byte();
+        buf[1] = self.mode.byte();
         buf[2] = r.byte();
         buf[3] = g.byte();
         buf[4] = b.byte();
-        // buf[5]: sound control
+        buf[5] = self.sound.byte();
+    }
+}
+
+impl HasDimmer for Strobe {
+    fn dimmer(&self) -> f64 {
+        self.alpha
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.alpha = fr;
     }
 }
 
-// pub enum StrobeMode {
-//     Manual,
-//     ColorCycle,
-//     Auto,
-// }
+impl HasColor for Strobe {
+    fn color(&self) -> Rgbw {
+        self.color.into()
+    }
+    fn set_color(&mut self, color: Rgbw) {
+        self.color = color.into();
+    }
+}
 
-// impl StrobeMode {
-//     pub fn byte(&self) -> u8 {
-//         match self {
-//             StrobeMode::Manual => 0,
-//             StrobeMode::ColorCycle => 159,
-//             StrobeMode::Auto => 60,
-//         }
-//     }
-// }
+impl StrobeMode {
+    pub fn byte(&self) -> u8 {
+        match self {
+            StrobeMode::Manual => 0,
+            StrobeMode::ColorCycle => 159,
+            StrobeMode::Auto => 60,
+            StrobeMode::Raw(i) => *i,
+        }
+    }
+}
 
 impl Default for Strobe {
     fn default() -> Self {
         Self {
-            // mode: StrobeMode::Manual,
+            mode: StrobeMode::Manual,
+            sound: 0.0,
             color: Rgb::BLACK,
             alpha: 1.0,
+            profile: Profile::default(),
         }
     }
 }
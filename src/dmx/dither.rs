@@ -0,0 +1,66 @@
+//! Temporal dithering, for smooth fades on 8-bit channels.
+//!
+//! Quantizing a slow fade to bytes makes each step visible near black. Instead, we carry each
+//! channel's truncation error over to the next frame, so over a few frames the output averages
+//! out to the full-precision level.
+
+use crate::dmx::Device;
+
+/// Dithers the continuous channels of devices in a DMX universe across frames.
+///
+/// Error is tracked per DMX address, so use one `Dither` per universe and encode every device
+/// at the same address each frame.
+#[derive(Clone, Debug, Default)]
+pub struct Dither {
+    error: Vec<f64>,
+    levels: Vec<Option<f64>>,
+}
+
+impl Dither {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encode a device into `buf` at `addr`, dithering any channels it reports [levels](Device::levels) for.
+    pub fn encode<D: Device + ?Sized>(&mut self, device: &D, buf: &mut [u8], addr: usize) {
+        let n = device.channels();
+        let buf = &mut buf[addr..addr + n];
+        device.encode(buf);
+
+        self.levels.clear();
+        self.levels.resize(n, None);
+        device.levels(&mut self.levels);
+
+        if self.error.len() < addr + n {
+            self.error.resize(addr + n, 0.0);
+        }
+        let error = &mut self.error[addr..addr + n];
+
+        for ((b, level), err) in buf.iter_mut().zip(&self.levels).zip(error) {
+            match *level {
+                Some(level) => *b = Self::quantize(level, err),
+                None => *err = 0.0,
+            }
+        }
+    }
+
+    /// Forget the accumulated error, e.g. after repatching.
+    pub fn reset(&mut self) {
+        self.error.clear();
+    }
+
+    /// Truncate a 0..1 level plus the previous frame's error to a byte like [`Interp::byte`](crate::num::Interp::byte),
+    /// keeping the new error.
+    fn quantize(level: f64, err: &mut f64) -> u8 {
+        // Hold full off and full on exactly, so black doesn't flicker.
+        if level <= 0.0 || level >= 1.0 {
+            *err = 0.0;
+            return if level <= 0.0 { 0 } else { 255 };
+        }
+
+        let x = level * 255.0 + *err;
+        let q = x.floor().clamp(0.0, 255.0);
+        *err = x - q;
+        q as u8
+    }
+}
@@ -0,0 +1,473 @@
+//! GDTF fixture description import.
+//!
+//! A `.gdtf` file is a zip archive containing a `description.xml` alongside
+//! models and wheel images. We only read the XML, extracting the DMX modes,
+//! channel functions, channel sets and geometry tree, which is enough to drive
+//! the fixture at runtime.
+//!
+//! See <https://gdtf.eu/gdtf/file-spec/file-format-definition/>
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::color::Rgbw;
+use crate::dmx::{Device, HasColor, HasDimmer, HasPanTilt, HasStrobe, HasZoom};
+use crate::num::{Interp, Range};
+
+/// A fixture type parsed from a GDTF description.
+#[derive(Clone, Debug)]
+pub struct FixtureType {
+    pub name: String,
+    pub short_name: String,
+    pub manufacturer: String,
+    pub description: String,
+
+    pub geometries: Vec<Geometry>,
+    pub modes: Vec<Mode>,
+}
+
+/// A node in the fixture's geometry tree, e.g. a `Base` containing a `Yoke` containing a `Head`.
+#[derive(Clone, Debug)]
+pub struct Geometry {
+    pub name: String,
+    /// The element kind, e.g. `Geometry`, `Axis` or `Beam`.
+    pub kind: String,
+    pub children: Vec<Geometry>,
+}
+
+/// A DMX mode, aka personality.
+#[derive(Clone, Debug)]
+pub struct Mode {
+    pub name: String,
+    /// Name of the root geometry this mode controls.
+    pub geometry: String,
+    pub channels: Vec<Channel>,
+}
+
+/// A DMX channel, possibly spanning several addresses for 16/24-bit resolution.
+#[derive(Clone, Debug)]
+pub struct Channel {
+    /// Name of the geometry this channel controls.
+    pub geometry: String,
+    /// 0-indexed addresses within the mode, most significant byte first. Empty for virtual channels.
+    pub offset: Vec<usize>,
+    /// The default value, at the channel's resolution.
+    pub default: u32,
+    /// The attribute of the channel's logical channel, e.g. `Dimmer` or `Pan`.
+    pub attribute: String,
+    pub functions: Vec<ChannelFunction>,
+}
+
+/// A function occupying a DMX range of a channel, e.g. `Shutter1Strobe` from 64..95.
+#[derive(Clone, Debug)]
+pub struct ChannelFunction {
+    pub name: String,
+    pub attribute: String,
+    /// The DMX range covered by this function, inclusive, at the channel's resolution.
+    pub from: u32,
+    pub to: u32,
+    /// The default value, at the channel's resolution.
+    pub default: u32,
+    /// The physical range this function maps onto, e.g. degrees for `Pan`.
+    pub physical: Range,
+    pub sets: Vec<ChannelSet>,
+}
+
+/// A named value or range within a channel function, e.g. `Open` or `Closed`.
+#[derive(Clone, Debug)]
+pub struct ChannelSet {
+    pub name: String,
+    /// The DMX range covered by this set, inclusive, at the channel's resolution.
+    pub from: u32,
+    pub to: u32,
+}
+
+impl FixtureType {
+    /// Read a `.gdtf` archive.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        Self::open_inner(path).with_context(|| format!("Failed to read GDTF {}", path.display()))
+    }
+
+    fn open_inner(path: &Path) -> Result<Self> {
+        let mut archive = zip::ZipArchive::new(File::open(path)?)?;
+        let mut xml = String::new();
+        archive.by_name("description.xml")?.read_to_string(&mut xml)?;
+        Self::parse(&xml)
+    }
+
+    /// Parse the contents of a `description.xml`.
+    pub fn parse(xml: &str) -> Result<Self> {
+        let doc = roxmltree::Document::parse(xml)?;
+        let ty = child(doc.root_element(), "FixtureType").context("Missing <FixtureType>")?;
+
+        let geometries = match child(ty, "Geometries") {
+            Some(node) => node.children().filter(|n| n.is_element()).map(parse_geometry).collect(),
+            None => vec![],
+        };
+        let modes = match child(ty, "DMXModes") {
+            Some(node) => children(node, "DMXMode").map(parse_mode).collect::<Result<_>>()?,
+            None => vec![],
+        };
+
+        Ok(Self {
+            name: attr(ty, "Name").to_string(),
+            short_name: attr(ty, "ShortName").to_string(),
+            manufacturer: attr(ty, "Manufacturer").to_string(),
+            description: attr(ty, "Description").to_string(),
+
+            geometries,
+            modes,
+        })
+    }
+
+    /// Find a mode by name.
+    pub fn mode(&self, name: &str) -> Option<&Mode> {
+        self.modes.iter().find(|m| m.name == name)
+    }
+}
+
+impl Mode {
+    /// Number of DMX addresses used by this mode.
+    pub fn footprint(&self) -> usize {
+        self.channels.iter().flat_map(|c| c.offset.iter()).map(|o| o + 1).max().unwrap_or(0)
+    }
+}
+
+impl Channel {
+    /// Number of bytes per value, e.g. 2 for 16-bit channels.
+    pub fn resolution(&self) -> usize {
+        self.offset.len().max(1)
+    }
+
+    /// The maximum value at the channel's resolution.
+    pub fn max(&self) -> u32 {
+        max_value(self.resolution())
+    }
+
+    /// Find the first function controlling an attribute.
+    pub fn function(&self, attribute: &str) -> Option<&ChannelFunction> {
+        self.functions.iter().find(|f| f.attribute == attribute)
+    }
+}
+
+impl ChannelFunction {
+    /// Find a channel set by name.
+    pub fn set(&self, name: &str) -> Option<&ChannelSet> {
+        self.sets.iter().find(|s| s.name == name)
+    }
+
+    /// Map 0..1 onto this function's DMX range.
+    pub fn value(&self, fr: f64) -> u32 {
+        fr.clamp(0.0, 1.0).lerp(self.from as f64..self.to as f64).round() as u32
+    }
+}
+
+/// A patched GDTF fixture running in one of its modes.
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    mode: Mode,
+    values: Vec<u32>,
+}
+
+impl Fixture {
+    /// Construct a fixture running the named mode, with every channel at its default.
+    pub fn new(ty: &FixtureType, mode: &str) -> Result<Self> {
+        let mode = ty.mode(mode).ok_or_else(|| anyhow!("No mode {mode:?} in {}", ty.name))?.clone();
+        let values = mode.channels.iter().map(|c| c.default).collect();
+        Ok(Self { mode, values })
+    }
+
+    /// The mode this fixture is running.
+    pub fn mode(&self) -> &Mode {
+        &self.mode
+    }
+
+    /// Set an attribute on every geometry to 0..1 of its function's range.
+    pub fn set(&mut self, attribute: &str, fr: f64) {
+        self.update(None, |c| c.function(attribute).map(|f| f.value(fr)));
+    }
+
+    /// Get an attribute as 0..1 of its function's range, from the first channel controlling it.
+    ///
+    /// Returns 0 if no channel controls it, or if the channel is currently in a different function.
+    pub fn get(&self, attribute: &str) -> f64 {
+        for (channel, &v) in self.mode.channels.iter().zip(&self.values) {
+            if let Some(f) = channel.function(attribute) {
+                if (f.from..=f.to).contains(&v) && f.to > f.from {
+                    return (v - f.from) as f64 / (f.to - f.from) as f64;
+                }
+                return 0.0;
+            }
+        }
+        0.0
+    }
+
+    /// Set an attribute on a single geometry to 0..1 of its function's range.
+    pub fn set_at(&mut self, geometry: &str, attribute: &str, fr: f64) {
+        self.update(Some(geometry), |c| c.function(attribute).map(|f| f.value(fr)));
+    }
+
+    /// Select a named channel set of an attribute on every geometry, e.g. `("Shutter1", "Open")`.
+    ///
+    /// Returns false if no channel has a matching set.
+    pub fn preset(&mut self, attribute: &str, set: &str) -> bool {
+        self.update(None, |c| {
+            let mut functions = c.functions.iter().filter(|f| f.attribute == attribute);
+            functions.find_map(|f| f.set(set)).map(|s| s.from)
+        })
+    }
+
+    /// Set a channel's raw value at its resolution. Ignored if the mode has no such channel.
+    pub fn raw(&mut self, channel: usize, value: u32) {
+        if let (Some(v), Some(c)) = (self.values.get_mut(channel), self.mode.channels.get(channel)) {
+            *v = value.min(c.max());
+        }
+    }
+
+    fn update(&mut self, geometry: Option<&str>, value: impl Fn(&Channel) -> Option<u32>) -> bool {
+        let mut found = false;
+        for (channel, v) in self.mode.channels.iter().zip(self.values.iter_mut()) {
+            if geometry.is_some_and(|g| g != channel.geometry) {
+                continue;
+            }
+            if let Some(x) = value(channel) {
+                *v = x;
+                found = true;
+            }
+        }
+        found
+    }
+}
+
+impl Device for Fixture {
+    fn channels(&self) -> usize {
+        self.mode.footprint()
+    }
+
+    fn encode(&self, buf: &mut [u8]) {
+        for (channel, &v) in self.mode.channels.iter().zip(&self.values) {
+            let n = channel.offset.len();
+            for (i, &o) in channel.offset.iter().enumerate() {
+                buf[o] = v.checked_shr(8 * (n - 1 - i) as u32).unwrap_or(0) as u8;
+            }
+        }
+    }
+}
+
+impl HasDimmer for Fixture {
+    fn dimmer(&self) -> f64 {
+        self.get("Dimmer")
+    }
+    fn set_dimmer(&mut self, fr: f64) {
+        self.set("Dimmer", fr);
+    }
+}
+
+/// Additive color mixing via the `ColorAdd_*` attributes.
+impl HasColor for Fixture {
+    fn color(&self) -> Rgbw {
+        Rgbw(self.get("ColorAdd_R"), self.get("ColorAdd_G"), self.get("ColorAdd_B"), self.get("ColorAdd_W"))
+    }
+    fn set_color(&mut self, Rgbw(r, g, b, w): Rgbw) {
+        self.set("ColorAdd_R", r);
+        self.set("ColorAdd_G", g);
+        self.set("ColorAdd_B", b);
+        self.set("ColorAdd_W", w);
+    }
+}
+
+impl HasPanTilt for Fixture {
+    fn pan(&self) -> f64 {
+        self.get("Pan")
+    }
+    fn tilt(&self) -> f64 {
+        self.get("Tilt")
+    }
+    fn set_pan(&mut self, fr: f64) {
+        self.set("Pan", fr);
+    }
+    fn set_tilt(&mut self, fr: f64) {
+        self.set("Tilt", fr);
+    }
+}
+
+/// Strobes via `Shutter1Strobe`, and opens the shutter when off.
+impl HasStrobe for Fixture {
+    fn strobe(&self) -> f64 {
+        self.get("Shutter1Strobe")
+    }
+    fn set_strobe(&mut self, fr: f64) {
+        if fr > 0.0 {
+            self.set("Shutter1Strobe", fr);
+        } else {
+            self.preset("Shutter1", "Open");
+        }
+    }
+}
+
+impl HasZoom for Fixture {
+    fn zoom(&self) -> f64 {
+        self.get("Zoom")
+    }
+    fn set_zoom(&mut self, fr: f64) {
+        self.set("Zoom", fr);
+    }
+}
+
+type Node<'a, 'i> = roxmltree::Node<'a, 'i>;
+
+fn child<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    node.children().find(|n| n.has_tag_name(name))
+}
+
+fn children<'a, 'i: 'a>(node: Node<'a, 'i>, name: &'a str) -> impl Iterator<Item = Node<'a, 'i>> + 'a {
+    node.children().filter(move |n| n.has_tag_name(name))
+}
+
+fn attr<'a>(node: Node<'a, '_>, name: &str) -> &'a str {
+    node.attribute(name).unwrap_or_default()
+}
+
+fn parse_geometry(node: Node) -> Geometry {
+    Geometry {
+        name: attr(node, "Name").to_string(),
+        kind: node.tag_name().name().to_string(),
+        children: node.children().filter(|n| n.is_element()).map(parse_geometry).collect(),
+    }
+}
+
+fn parse_mode(node: Node) -> Result<Mode> {
+    let name = attr(node, "Name").to_string();
+    let channels = match child(node, "DMXChannels") {
+        Some(node) => children(node, "DMXChannel").map(parse_channel).collect::<Result<Vec<_>>>(),
+        None => Ok(vec![]),
+    };
+
+    Ok(Mode {
+        channels: channels.with_context(|| format!("Invalid DMX mode {name:?}"))?,
+        geometry: attr(node, "Geometry").to_string(),
+        name,
+    })
+}
+
+fn parse_channel(node: Node) -> Result<Channel> {
+    // Channels in other breaks are patched separately, which we don't support.
+    let offset = match attr(node, "DMXBreak") {
+        "" | "1" | "Overwrite" => parse_offset(attr(node, "Offset"))?,
+        _ => vec![],
+    };
+    let resolution = offset.len().max(1);
+
+    // A channel can have several logical channels, each with its own range of functions.
+    let logical = child(node, "LogicalChannel").context("Missing <LogicalChannel>")?;
+    let mut functions = children(node, "LogicalChannel")
+        .flat_map(|l| children(l, "ChannelFunction"))
+        .map(|n| parse_function(n, resolution))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Each function runs until the next one starts, and likewise for the sets within a function.
+    functions.sort_by_key(|f| f.from);
+    let to = ends(functions.iter().map(|f| f.from), max_value(resolution));
+    for (f, to) in functions.iter_mut().zip(to) {
+        f.to = to;
+        let to = ends(f.sets.iter().map(|s| s.from), to);
+        for (s, to) in f.sets.iter_mut().zip(to) {
+            s.to = to;
+        }
+    }
+
+    // GDTF 1.0 puts the default on the channel, 1.1 puts it on the initial function.
+    let default = match node.attribute("Default") {
+        Some(v) => parse_value(v, resolution)?,
+        None => functions.first().map(|f| f.default).unwrap_or(0),
+    };
+
+    Ok(Channel {
+        geometry: attr(node, "Geometry").to_string(),
+        offset,
+        default,
+        attribute: attr(logical, "Attribute").to_string(),
+        functions,
+    })
+}
+
+fn parse_function(node: Node, resolution: usize) -> Result<ChannelFunction> {
+    let from = parse_value(attr(node, "DMXFrom"), resolution)?;
+    let mut sets = children(node, "ChannelSet")
+        .map(|n| {
+            Ok(ChannelSet {
+                name: attr(n, "Name").to_string(),
+                from: parse_value(attr(n, "DMXFrom"), resolution)?,
+                to: 0,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    sets.sort_by_key(|s| s.from);
+
+    Ok(ChannelFunction {
+        name: attr(node, "Name").to_string(),
+        attribute: attr(node, "Attribute").to_string(),
+        from,
+        to: 0,
+        default: parse_value(attr(node, "Default"), resolution)?,
+        physical: Range {
+            lo: attr(node, "PhysicalFrom").parse().unwrap_or(0.0),
+            hi: attr(node, "PhysicalTo").parse().unwrap_or(1.0),
+        },
+        sets,
+    })
+}
+
+/// Parse a 1-indexed `Offset` like `"1,2"` into 0-indexed addresses, up to 4 for 32-bit channels.
+fn parse_offset(s: &str) -> Result<Vec<usize>> {
+    if s.is_empty() || s == "None" {
+        return Ok(vec![]);
+    }
+    let offset = s
+        .split(',')
+        .map(|o| match o.trim().parse::<usize>() {
+            Ok(o) if o > 0 => Ok(o - 1),
+            _ => bail!("Invalid offset {s:?}"),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if offset.len() > 4 {
+        bail!("Offset {s:?} is wider than 32 bits");
+    }
+    Ok(offset)
+}
+
+/// Parse a DMX value like `"128/1"` and scale it to the given resolution in bytes.
+fn parse_value(s: &str, resolution: usize) -> Result<u32> {
+    if s.is_empty() || s == "None" {
+        return Ok(0);
+    }
+    let (value, bytes) = s.split_once('/').with_context(|| format!("Invalid DMX value {s:?}"))?;
+    let value: u32 = value.parse().with_context(|| format!("Invalid DMX value {s:?}"))?;
+    // A trailing `s` means the value is byte-mirrored rather than shifted when scaling up.
+    let (bytes, mirror) = match bytes.strip_suffix('s') {
+        Some(bytes) => (bytes, true),
+        None => (bytes, false),
+    };
+    let bytes: usize = bytes.parse().with_context(|| format!("Invalid DMX value {s:?}"))?;
+
+    let mut out = value;
+    for _ in bytes..resolution {
+        out = (out << 8) | if mirror { out & 0xff } else { 0 };
+    }
+    for _ in resolution..bytes {
+        out >>= 8;
+    }
+    Ok(out)
+}
+
+/// The inclusive end of each range, given the sorted starts of each range and the maximum value.
+fn ends(froms: impl Iterator<Item = u32>, max: u32) -> Vec<u32> {
+    froms.skip(1).map(|from| from.saturating_sub(1)).chain([max]).collect()
+}
+
+fn max_value(resolution: usize) -> u32 {
+    (1u64 << (8 * resolution.min(4))).wrapping_sub(1) as u32
+}
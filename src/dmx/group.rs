@@ -0,0 +1,165 @@
+//! Groups of fixtures laid out in space, for fanning effects out across them.
+//!
+//! Groups are generic over the fixture type, so a group of mixed fixtures can hold e.g.
+//! `Box<dyn HasColor>`, or be built each frame from `&mut dyn HasColor` borrowed from elsewhere.
+
+use crate::num::{Interp, Vec3};
+
+/// A fixture in a group, with its index for chases and position for waves.
+#[derive(Clone, Debug)]
+pub struct Member<T> {
+    pub fixture: T,
+    /// Order in the group, from `0..len`.
+    pub index: usize,
+    pub pos: Vec3,
+}
+
+/// A group of fixtures with a spatial layout.
+#[derive(Clone, Debug)]
+pub struct Group<T> {
+    members: Vec<Member<T>>,
+}
+
+impl<T> Default for Group<T> {
+    fn default() -> Self {
+        Self { members: vec![] }
+    }
+}
+
+impl<T> Group<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lay out fixtures evenly along a line from 0..1 on the x axis.
+    pub fn line<I: IntoIterator<Item = T>>(fixtures: I) -> Self {
+        let fixtures: Vec<T> = fixtures.into_iter().collect();
+        let n = fixtures.len();
+        let mut group = Self::new();
+        for (i, fixture) in fixtures.into_iter().enumerate() {
+            group.push(fixture, fraction(i, n));
+        }
+        group
+    }
+
+    /// Lay out fixtures in rows of `cols` on the xy plane, from 0..1 on each axis. At least 1 column.
+    pub fn grid<I: IntoIterator<Item = T>>(fixtures: I, cols: usize) -> Self {
+        let fixtures: Vec<T> = fixtures.into_iter().collect();
+        let cols = cols.max(1);
+        let rows = fixtures.len().div_ceil(cols);
+        let mut group = Self::new();
+        for (i, fixture) in fixtures.into_iter().enumerate() {
+            group.push(fixture, (fraction(i % cols, cols), fraction(i / cols, rows)));
+        }
+        group
+    }
+
+    /// Add a fixture at a 1D, 2D or 3D position, e.g. `1.0`, `(1.0, 2.0)` or `(1.0, 2.0, 3.0)`.
+    pub fn push<P: Into<Vec3>>(&mut self, fixture: T, pos: P) {
+        let index = self.members.len();
+        self.members.push(Member { fixture, index, pos: pos.into() });
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Member<T>> {
+        self.members.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Member<T>> {
+        self.members.iter_mut()
+    }
+
+    /// Iterate over just the fixtures.
+    pub fn fixtures(&self) -> impl Iterator<Item = &T> {
+        self.members.iter().map(|m| &m.fixture)
+    }
+
+    /// Iterate mutably over just the fixtures.
+    pub fn fixtures_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.members.iter_mut().map(|m| &mut m.fixture)
+    }
+
+    /// Call `f` with each fixture and its index normalized to 0..1.
+    pub fn each(&mut self, mut f: impl FnMut(&mut T, f64)) {
+        let n = self.len();
+        for m in &mut self.members {
+            f(&mut m.fixture, fraction(m.index, n));
+        }
+    }
+
+    /// Call `f` with each fixture and its position normalized into the group's bounds, 0..1 on each axis.
+    pub fn each_pos(&mut self, mut f: impl FnMut(&mut T, Vec3)) {
+        let (lo, hi) = self.bounds();
+        let size = hi - lo;
+        for m in &mut self.members {
+            let d = m.pos - lo;
+            let pos = Vec3::new(ratio(d.x, size.x), ratio(d.y, size.y), ratio(d.z, size.z));
+            f(&mut m.fixture, pos);
+        }
+    }
+
+    /// Call `f` with each fixture and `t` phase shifted by its index, for chases.
+    ///
+    /// `spread` is the fraction of the period `pd` spread across the group, so with `1.0`
+    /// the fixtures are evenly distributed around one full cycle.
+    pub fn phase(&mut self, t: f64, pd: f64, spread: f64, mut f: impl FnMut(&mut T, f64)) {
+        let n = self.len().max(1) as f64;
+        for m in &mut self.members {
+            f(&mut m.fixture, t.phase(pd, spread * m.index as f64 / n));
+        }
+    }
+
+    /// Call `f` with each fixture and `t` phase shifted by its position along `dir`, for waves.
+    ///
+    /// `spread` is the fraction of the period `pd` between the first and last fixture along `dir`.
+    pub fn phase_along<D: Into<Vec3>>(&mut self, t: f64, pd: f64, spread: f64, dir: D, mut f: impl FnMut(&mut T, f64)) {
+        let dir = dir.into();
+        let (lo, hi) = self
+            .members
+            .iter()
+            .map(|m| m.pos.dot(dir))
+            .fold((f64::MAX, f64::MIN), |(lo, hi), d| (lo.min(d), hi.max(d)));
+        for m in &mut self.members {
+            let fr = ratio(m.pos.dot(dir) - lo, hi - lo);
+            f(&mut m.fixture, t.phase(pd, spread * fr));
+        }
+    }
+
+    /// The lowest and highest corners of the group's bounding box.
+    pub fn bounds(&self) -> (Vec3, Vec3) {
+        let mut it = self.members.iter().map(|m| m.pos);
+        let first = it.next().unwrap_or_default();
+        it.fold((first, first), |(lo, hi), p| (lo.min(p), hi.max(p)))
+    }
+}
+
+impl<T> FromIterator<T> for Group<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::line(iter)
+    }
+}
+
+/// `i` of `n` evenly spaced from 0..1.
+fn fraction(i: usize, n: usize) -> f64 {
+    if n > 1 {
+        i as f64 / (n - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+/// `v / extent`, or 0 for a zero-size extent.
+fn ratio(v: f64, extent: f64) -> f64 {
+    if extent > 0.0 {
+        v / extent
+    } else {
+        0.0
+    }
+}
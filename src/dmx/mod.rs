@@ -1,6 +1,21 @@
 pub mod device;
 pub use device::Device;
 
+pub mod capability;
+pub use capability::{HasColor, HasDimmer, HasPanTilt, HasStrobe, HasZoom};
+
+pub mod group;
+pub use group::Group;
+
+pub mod profile;
+pub use profile::Profile;
+
+pub mod dither;
+pub use dither::Dither;
+
+#[cfg(feature = "gdtf")]
+pub mod gdtf;
+
 // pub struct DMX {
 //     buffer: Vec<u8>,
 // }
@@ -0,0 +1,118 @@
+//! Output profiles, for matching fixtures with different low-end response and color balance.
+//!
+//! Drivers pass their dimmer and color fields through their [`Profile`] before converting
+//! them to bytes, so each patched instance can be calibrated separately.
+//!
+//! With a measured [`Calibration`], colors are solved in CIE XYZ against the fixture's emitters
+//! rather than mapped channel by channel, so the same color matches across brands.
+
+use crate::color::{Calibration, Emitters, Rgb, Rgbw, Xyz};
+use crate::num::Interp;
+
+/// Per-fixture output calibration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Profile {
+    /// Response curve for dimmer channels.
+    pub dimmer: Curve,
+    /// Response curve for color channels, applied after `trim`.
+    pub color: Curve,
+    /// Levels above zero are raised into `min..1`, for fixtures which stay dark below a threshold.
+    pub min: f64,
+    /// Per-emitter scale for white balance, e.g. `Rgbw(1.0, 0.9, 0.8, 1.0)` to warm up a cool fixture.
+    pub trim: Rgbw,
+    /// Measured emitters, for mapping colors by chromaticity instead of by channel.
+    pub calibration: Option<Calibration>,
+}
+
+/// A response curve mapping 0..1 onto 0..1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Curve {
+    Linear,
+    /// `fr^gamma`, e.g. `2.2` to perceptually linearize an LED.
+    Gamma(f64),
+    Lut(Lut),
+}
+
+/// A response curve measured at evenly spaced points from 0..1, linearly interpolated between them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lut(pub [f64; Lut::POINTS]);
+
+impl Profile {
+    /// Apply the dimmer curve and minimum to a dimmer level.
+    pub fn dimmer(&self, fr: f64) -> f64 {
+        self.min(self.dimmer.apply(fr))
+    }
+
+    /// Map a color onto a fixture's emitters, solving against the calibration if there is one.
+    ///
+    /// The levels still need passing through [`Profile::color`] or [`Profile::rgb`].
+    pub fn map(&self, emitters: Emitters, color: Rgbw) -> [f64; Emitters::MAX] {
+        match &self.calibration {
+            Some(cal) => cal.solve(emitters, Xyz::from(color)),
+            None => emitters.map(color),
+        }
+    }
+
+    /// Apply the trim, color curve and minimum to each emitter of a color.
+    pub fn color(&self, Rgbw(r, g, b, w): Rgbw) -> Rgbw {
+        let Rgbw(tr, tg, tb, tw) = self.trim;
+        Rgbw(self.emitter(r * tr), self.emitter(g * tg), self.emitter(b * tb), self.emitter(w * tw))
+    }
+
+    /// Apply the trim, color curve and minimum to each emitter of an RGB color.
+    pub fn rgb(&self, Rgb(r, g, b): Rgb) -> Rgb {
+        let Rgbw(tr, tg, tb, _) = self.trim;
+        Rgb(self.emitter(r * tr), self.emitter(g * tg), self.emitter(b * tb))
+    }
+
+    fn emitter(&self, fr: f64) -> f64 {
+        self.min(self.color.apply(fr))
+    }
+
+    fn min(&self, fr: f64) -> f64 {
+        if fr > 0.0 {
+            fr.lerp(self.min..1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            dimmer: Curve::Linear,
+            color: Curve::Linear,
+            min: 0.0,
+            trim: Rgbw(1.0, 1.0, 1.0, 1.0),
+            calibration: None,
+        }
+    }
+}
+
+impl Curve {
+    pub fn apply(&self, fr: f64) -> f64 {
+        let fr = fr.clamp(0.0, 1.0);
+        match self {
+            Curve::Linear => fr,
+            Curve::Gamma(gamma) => fr.powf(*gamma),
+            Curve::Lut(lut) => lut.apply(fr),
+        }
+    }
+}
+
+impl Lut {
+    /// Number of points in the table.
+    pub const POINTS: usize = 17;
+
+    /// Sample a function at each point.
+    pub fn from_fn(f: impl Fn(f64) -> f64) -> Self {
+        Self(std::array::from_fn(|i| f(i as f64 / (Self::POINTS - 1) as f64)))
+    }
+
+    pub fn apply(&self, fr: f64) -> f64 {
+        let x = fr.clamp(0.0, 1.0) * (Self::POINTS - 1) as f64;
+        let i = (x as usize).min(Self::POINTS - 2);
+        (x - i as f64).lerp(self.0[i]..self.0[i + 1])
+    }
+}
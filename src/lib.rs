@@ -19,6 +19,7 @@ pub mod osc;
 
 pub mod color;
 pub mod num;
+pub mod time;
 
 /// A set of common traits and types. Bring in scope with `use prelude::*`.
 pub mod prelude {
@@ -1,9 +1,14 @@
-use crate::num::TAU_4;
+use crate::color::{Rgb, Rgbw};
+use crate::num::{Vec2, Vec3, TAU, TAU_2, TAU_4};
 
-/// Easing functions for floats in `0.0..1.0`, mirroring those from CSS.
+/// Easing functions for floats in `0.0..1.0`, mirroring those from CSS. Vectors and colors ease
+/// each component.
 ///
 /// See <https://easings.net/>
 pub trait Ease: Sized {
+    /// Apply an easing by value.
+    fn ease(self, easing: Easing) -> Self;
+
     fn in_quad(self) -> Self;
     fn out_quad(self) -> Self;
     fn inout_quad(self) -> Self;
@@ -23,9 +28,125 @@ pub trait Ease: Sized {
     fn in_sin(self) -> Self;
     fn out_sin(self) -> Self;
     fn inout_sin(self) -> Self;
+
+    fn in_quint(self) -> Self;
+    fn out_quint(self) -> Self;
+    fn inout_quint(self) -> Self;
+
+    fn in_circ(self) -> Self;
+    fn out_circ(self) -> Self;
+    fn inout_circ(self) -> Self;
+
+    /// Overshoots backwards before moving forwards.
+    fn in_back(self) -> Self;
+    fn out_back(self) -> Self;
+    fn inout_back(self) -> Self;
+
+    fn in_elastic(self) -> Self;
+    fn out_elastic(self) -> Self;
+    fn inout_elastic(self) -> Self;
+
+    fn in_bounce(self) -> Self;
+    fn out_bounce(self) -> Self;
+    fn inout_bounce(self) -> Self;
+
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`, with `x1` and `x2` in `0.0..1.0`.
+    fn cubic_bezier(self, x1: f64, y1: f64, x2: f64, y2: f64) -> Self;
+    /// CSS `steps(n, jump)`.
+    fn steps(self, n: u32, jump: Jump) -> Self;
+}
+
+/// Which ends of an easing are eased.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dir {
+    #[default]
+    In,
+    Out,
+    InOut,
+}
+
+/// Where the jumps of a CSS `steps()` easing fall.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Jump {
+    /// Jump at the start of each step, so it ends at 1 before the end.
+    Start,
+    /// Jump at the end of each step, so it starts at 0 and reaches 1 at the end.
+    #[default]
+    End,
+    /// Hold 0 for the first step and 1 for the last.
+    None,
+    /// Jump at both ends, never holding 0 or 1.
+    Both,
+}
+
+/// An easing function as a value, for storing in cues.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    #[default]
+    Linear,
+    Quad(Dir),
+    Cubic(Dir),
+    Quartic(Dir),
+    Quint(Dir),
+    Exp(Dir),
+    Sin(Dir),
+    Circ(Dir),
+    Back(Dir),
+    Elastic(Dir),
+    Bounce(Dir),
+    CubicBezier(f64, f64, f64, f64),
+    Steps(u32, Jump),
+}
+
+impl Easing {
+    /// CSS `ease`.
+    pub const EASE: Self = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+    /// CSS `ease-in`.
+    pub const EASE_IN: Self = Easing::CubicBezier(0.42, 0.0, 1.0, 1.0);
+    /// CSS `ease-out`.
+    pub const EASE_OUT: Self = Easing::CubicBezier(0.0, 0.0, 0.58, 1.0);
+    /// CSS `ease-in-out`.
+    pub const EASE_IN_OUT: Self = Easing::CubicBezier(0.42, 0.0, 0.58, 1.0);
+    /// CSS `step-start`.
+    pub const STEP_START: Self = Easing::Steps(1, Jump::Start);
+    /// CSS `step-end`.
+    pub const STEP_END: Self = Easing::Steps(1, Jump::End);
+
+    pub fn apply(self, fr: f64) -> f64 {
+        fn dir(fr: f64, d: Dir, i: fn(f64) -> f64, o: fn(f64) -> f64, io: fn(f64) -> f64) -> f64 {
+            match d {
+                Dir::In => i(fr),
+                Dir::Out => o(fr),
+                Dir::InOut => io(fr),
+            }
+        }
+
+        match self {
+            Easing::Linear => fr,
+            Easing::Quad(d) => dir(fr, d, f64::in_quad, f64::out_quad, f64::inout_quad),
+            Easing::Cubic(d) => dir(fr, d, f64::in_cubic, f64::out_cubic, f64::inout_cubic),
+            Easing::Quartic(d) => dir(fr, d, f64::in_quartic, f64::out_quartic, f64::inout_quartic),
+            Easing::Quint(d) => dir(fr, d, f64::in_quint, f64::out_quint, f64::inout_quint),
+            Easing::Exp(d) => dir(fr, d, f64::in_exp, f64::out_exp, f64::inout_exp),
+            Easing::Sin(d) => dir(fr, d, f64::in_sin, f64::out_sin, f64::inout_sin),
+            Easing::Circ(d) => dir(fr, d, f64::in_circ, f64::out_circ, f64::inout_circ),
+            Easing::Back(d) => dir(fr, d, f64::in_back, f64::out_back, f64::inout_back),
+            Easing::Elastic(d) => dir(fr, d, f64::in_elastic, f64::out_elastic, f64::inout_elastic),
+            Easing::Bounce(d) => dir(fr, d, f64::in_bounce, f64::out_bounce, f64::inout_bounce),
+            Easing::CubicBezier(x1, y1, x2, y2) => fr.cubic_bezier(x1, y1, x2, y2),
+            Easing::Steps(n, jump) => fr.steps(n, jump),
+        }
+    }
 }
 
 impl Ease for f64 {
+    fn ease(self, easing: Easing) -> f64 {
+        easing.apply(self)
+    }
+
     fn in_quad(self) -> f64 {
         self * self
     }
@@ -78,11 +199,7 @@ impl Ease for f64 {
         (self * TAU_4).sin()
     }
     fn inout_sin(self) -> f64 {
-        if self < 0.5 {
-            0.5 * (1. - (self * self).mul_add(-4., 1.).sqrt())
-        } else {
-            0.5 * ((self.mul_add(-2., 3.) * self.mul_add(2., -1.)).sqrt() + 1.)
-        }
+        (1. - (self * TAU_2).cos()) / 2.
     }
     fn in_exp(self) -> f64 {
         if self == 0. {
@@ -109,4 +226,234 @@ impl Ease for f64 {
             self.mul_add(-20., 10.).exp2().mul_add(-0.5, 1.)
         }
     }
+
+    fn in_quint(self) -> f64 {
+        self * self * self * self * self
+    }
+    fn out_quint(self) -> f64 {
+        1. - (1. - self).powi(5)
+    }
+    fn inout_quint(self) -> f64 {
+        if self < 0.5 {
+            16. * self.powi(5)
+        } else {
+            1. - self.mul_add(-2., 2.).powi(5) / 2.
+        }
+    }
+
+    fn in_circ(self) -> f64 {
+        1. - (1. - self * self).sqrt()
+    }
+    fn out_circ(self) -> f64 {
+        (1. - (self - 1.).powi(2)).sqrt()
+    }
+    fn inout_circ(self) -> f64 {
+        if self < 0.5 {
+            (1. - (1. - (2. * self).powi(2)).sqrt()) / 2.
+        } else {
+            ((1. - self.mul_add(-2., 2.).powi(2)).sqrt() + 1.) / 2.
+        }
+    }
+
+    fn in_back(self) -> f64 {
+        const C1: f64 = 1.70158;
+        (C1 + 1.) * self * self * self - C1 * self * self
+    }
+    fn out_back(self) -> f64 {
+        const C1: f64 = 1.70158;
+        let y = self - 1.;
+        1. + (C1 + 1.) * y * y * y + C1 * y * y
+    }
+    fn inout_back(self) -> f64 {
+        const C2: f64 = 1.70158 * 1.525;
+        if self < 0.5 {
+            (2. * self).powi(2) * ((C2 + 1.) * 2. * self - C2) / 2.
+        } else {
+            ((2. * self - 2.).powi(2) * ((C2 + 1.) * (2. * self - 2.) + C2) + 2.) / 2.
+        }
+    }
+
+    fn in_elastic(self) -> f64 {
+        if self == 0. || self == 1. {
+            self
+        } else {
+            -(10. * self - 10.).exp2() * ((10. * self - 10.75) * (TAU / 3.)).sin()
+        }
+    }
+    fn out_elastic(self) -> f64 {
+        if self == 0. || self == 1. {
+            self
+        } else {
+            (-10. * self).exp2() * ((10. * self - 0.75) * (TAU / 3.)).sin() + 1.
+        }
+    }
+    fn inout_elastic(self) -> f64 {
+        let s = ((20. * self - 11.125) * (TAU / 4.5)).sin();
+        if self == 0. || self == 1. {
+            self
+        } else if self < 0.5 {
+            -(20. * self - 10.).exp2() * s / 2.
+        } else {
+            (-20. * self + 10.).exp2() * s / 2. + 1.
+        }
+    }
+
+    fn in_bounce(self) -> f64 {
+        1. - (1. - self).out_bounce()
+    }
+    fn out_bounce(self) -> f64 {
+        const N: f64 = 7.5625;
+        const D: f64 = 2.75;
+        if self < 1. / D {
+            N * self * self
+        } else if self < 2. / D {
+            let y = self - 1.5 / D;
+            N * y * y + 0.75
+        } else if self < 2.5 / D {
+            let y = self - 2.25 / D;
+            N * y * y + 0.9375
+        } else {
+            let y = self - 2.625 / D;
+            N * y * y + 0.984375
+        }
+    }
+    fn inout_bounce(self) -> f64 {
+        if self < 0.5 {
+            (1. - (1. - 2. * self).out_bounce()) / 2.
+        } else {
+            (1. + (2. * self - 1.).out_bounce()) / 2.
+        }
+    }
+
+    fn cubic_bezier(self, x1: f64, y1: f64, x2: f64, y2: f64) -> f64 {
+        // Polynomial coefficients, as in WebKit's UnitBezier.
+        let (x1, x2) = (x1.clamp(0., 1.), x2.clamp(0., 1.));
+        let (cx, cy) = (3. * x1, 3. * y1);
+        let (bx, by) = (3. * (x2 - x1) - cx, 3. * (y2 - y1) - cy);
+        let (ax, ay) = (1. - cx - bx, 1. - cy - by);
+        let x_at = |t: f64| ((ax * t + bx) * t + cx) * t;
+        let y_at = |t: f64| ((ay * t + by) * t + cy) * t;
+        let dx_at = |t: f64| (3. * ax * t + 2. * bx) * t + cx;
+
+        let x = self.clamp(0., 1.);
+
+        // Newton's method usually converges in a few steps, with bisection as a fallback.
+        let mut t = x;
+        for _ in 0..8 {
+            let err = x_at(t) - x;
+            if err.abs() < 1e-7 {
+                return y_at(t);
+            }
+            let d = dx_at(t);
+            if d.abs() < 1e-6 {
+                break;
+            }
+            t -= err / d;
+        }
+
+        let (mut lo, mut hi) = (0., 1.);
+        t = x;
+        while lo < hi {
+            let v = x_at(t);
+            if (v - x).abs() < 1e-7 {
+                break;
+            }
+            if x > v {
+                lo = t;
+            } else {
+                hi = t;
+            }
+            t = (lo + hi) / 2.;
+            if hi - lo < 1e-12 {
+                break;
+            }
+        }
+        y_at(t)
+    }
+
+    fn steps(self, n: u32, jump: Jump) -> f64 {
+        let n = n.max(1) as f64;
+        let mut step = (self * n).floor();
+        if matches!(jump, Jump::Start | Jump::Both) {
+            step += 1.;
+        }
+        let jumps = match jump {
+            Jump::Start | Jump::End => n,
+            Jump::None => (n - 1.).max(1.),
+            Jump::Both => n + 1.,
+        };
+        if self >= 0. && step < 0. {
+            step = 0.;
+        }
+        if self <= 1. && step > jumps {
+            step = jumps;
+        }
+        step / jumps
+    }
 }
+
+/// Implement [`Ease`] with `$apply!(self, |v| ...)`, which applies an easing to a value.
+macro_rules! impl_ease {
+    (impl[$($gen:tt)*] for $ty:ty, $apply:ident) => {
+        impl_ease!(impl[$($gen)*] for $ty, $apply;
+            in_quad, out_quad, inout_quad, in_cubic, out_cubic, inout_cubic,
+            in_quartic, out_quartic, inout_quartic, in_exp, out_exp, inout_exp,
+            in_sin, out_sin, inout_sin, in_quint, out_quint, inout_quint,
+            in_circ, out_circ, inout_circ, in_back, out_back, inout_back,
+            in_elastic, out_elastic, inout_elastic, in_bounce, out_bounce, inout_bounce
+        );
+    };
+    (impl[$($gen:tt)*] for $ty:ty, $apply:ident; $($method:ident),*) => {
+        impl<$($gen)*> Ease for $ty {
+            fn ease(self, easing: Easing) -> Self {
+                $apply!(self, |v| v.ease(easing))
+            }
+
+            $(
+                fn $method(self) -> Self {
+                    $apply!(self, |v| v.$method())
+                }
+            )*
+
+            fn cubic_bezier(self, x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+                $apply!(self, |v| v.cubic_bezier(x1, y1, x2, y2))
+            }
+            fn steps(self, n: u32, jump: Jump) -> Self {
+                $apply!(self, |v| v.steps(n, jump))
+            }
+        }
+    };
+}
+
+/// Ease each component.
+macro_rules! each {
+    ($v:expr, $f:expr) => {
+        $v.map($f)
+    };
+}
+
+/// Ease through `f64`.
+macro_rules! as_f64 {
+    ($v:expr, $f:expr) => {
+        as_f64($v, $f)
+    };
+}
+
+fn as_f64(v: f32, f: impl Fn(f64) -> f64) -> f32 {
+    f(v.into()) as f32
+}
+
+/// Ease through an array of components.
+macro_rules! as_array {
+    ($v:expr, $f:expr) => {
+        Self::from(<[f64; _]>::from($v).map($f))
+    };
+}
+
+impl_ease!(impl[T: Ease, const N: usize] for [T; N], each);
+impl_ease!(impl[] for f32, as_f64);
+impl_ease!(impl[] for (f64, f64), as_array);
+impl_ease!(impl[] for Vec2, as_array);
+impl_ease!(impl[] for Vec3, as_array);
+impl_ease!(impl[] for Rgb, as_array);
+impl_ease!(impl[] for Rgbw, as_array);
@@ -0,0 +1,158 @@
+//! Envelope generators, for lights that attack and decay like a synth voice.
+//!
+//! An [`Envelope`] is gated on and off with timestamps, e.g. from a Launchpad:
+//!
+//! ```ignore
+//! match input {
+//!     Input::Press(_, vel) => env.gate_on(t, vel),
+//!     Input::Release(_) => env.gate_off(t),
+//!     _ => {}
+//! }
+//! ```
+//!
+//! and then sampled each frame with [`Envelope::at`].
+
+use crate::num::{Ease, Easing};
+
+/// One stage of an envelope: how long it takes, and the curve it follows.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment {
+    pub dur: f64,
+    pub ease: Easing,
+}
+
+/// Attack, decay, sustain, release.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adsr {
+    /// Rise to the peak after gating on.
+    pub attack: Segment,
+    /// Fall from the peak to `sustain`.
+    pub decay: Segment,
+    /// Level held while gated on, as a fraction of the peak.
+    pub sustain: f64,
+    /// Fall to 0 after gating off.
+    pub release: Segment,
+    /// How much velocity scales the peak, from 0 for not at all to 1 for fully.
+    pub velocity: f64,
+}
+
+/// A running envelope, gated on and off over time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Envelope {
+    pub shape: Adsr,
+    /// When the gate went on, and the level at the time to attack from.
+    on: Option<(f64, f64)>,
+    /// When the gate went off.
+    off: Option<f64>,
+    vel: f64,
+}
+
+impl Segment {
+    pub fn new(dur: f64, ease: Easing) -> Self {
+        Self { dur, ease }
+    }
+
+    pub fn linear(dur: f64) -> Self {
+        Self::new(dur, Easing::Linear)
+    }
+
+    /// Ease from `a` to `b` at time `t` into the segment.
+    fn at(&self, t: f64, a: f64, b: f64) -> f64 {
+        if t >= self.dur {
+            return b;
+        }
+        let fr = (t / self.dur).clamp(0.0, 1.0).ease(self.ease);
+        a + fr * (b - a)
+    }
+}
+
+impl Adsr {
+    pub fn new(attack: Segment, decay: Segment, sustain: f64, release: Segment) -> Self {
+        Self { attack, decay, sustain, release, velocity: 1.0 }
+    }
+
+    /// Attack to the peak and hold it while gated on, then release.
+    pub fn ar(attack: Segment, release: Segment) -> Self {
+        Self::new(attack, Segment::default(), 1.0, release)
+    }
+}
+
+impl Default for Adsr {
+    fn default() -> Self {
+        Self::ar(Segment::default(), Segment::default())
+    }
+}
+
+impl Envelope {
+    pub fn new(shape: Adsr) -> Self {
+        Self { shape, on: None, off: None, vel: 1.0 }
+    }
+
+    /// Gate on with a velocity in `0.0..1.0`, attacking from wherever the envelope is now.
+    pub fn gate_on(&mut self, t: f64, vel: f64) {
+        let from = self.at(t);
+        self.on = Some((t, from));
+        self.off = None;
+        self.vel = vel.clamp(0.0, 1.0);
+    }
+
+    /// Gate off, releasing from wherever the envelope is now.
+    pub fn gate_off(&mut self, t: f64) {
+        if self.on.is_some() && self.off.is_none() {
+            self.off = Some(t);
+        }
+    }
+
+    /// Gate on, then off again once the attack and decay finish, for a one-shot hit.
+    pub fn trigger(&mut self, t: f64, vel: f64) {
+        self.gate_on(t, vel);
+        self.off = Some(t + self.shape.attack.dur + self.shape.decay.dur);
+    }
+
+    /// Whether the gate is on at `t`.
+    pub fn is_on(&self, t: f64) -> bool {
+        self.on.is_some() && self.off.is_none_or(|off| t < off)
+    }
+
+    /// Whether the envelope has released all the way to 0 by `t`.
+    pub fn is_done(&self, t: f64) -> bool {
+        match (self.on, self.off) {
+            (None, _) => true,
+            (Some(_), Some(off)) => t >= off + self.shape.release.dur,
+            (Some(_), None) => false,
+        }
+    }
+
+    /// The level at time `t`, in `0.0..1.0`.
+    pub fn at(&self, t: f64) -> f64 {
+        match self.off {
+            Some(off) if t >= off => self.shape.release.at(t - off, self.held(off), 0.0),
+            _ => self.held(t),
+        }
+    }
+
+    /// The level at `t` as if the gate were still on.
+    fn held(&self, t: f64) -> f64 {
+        let Some((on, from)) = self.on else {
+            return 0.0;
+        };
+        let Adsr { attack, decay, sustain, velocity, .. } = self.shape;
+        let peak = 1.0 - velocity.clamp(0.0, 1.0) * (1.0 - self.vel);
+
+        let t = t - on;
+        if t < attack.dur {
+            attack.at(t, from, peak)
+        } else {
+            decay.at(t - attack.dur, peak, peak * sustain)
+        }
+    }
+}
+
+impl From<Adsr> for Envelope {
+    fn from(shape: Adsr) -> Self {
+        Self::new(shape)
+    }
+}
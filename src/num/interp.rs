@@ -1,10 +1,17 @@
-use crate::num::{Range, TAU_2};
+use crate::color::{Rgb, Rgbw};
+use crate::num::{Range, Vec2, Vec3, TAU_2};
 
+/// Interpolation on floats, and on small vectors and colors component by component.
 pub trait Interp: Sized {
+    /// `bool` for floats, or one per component.
+    type Bool;
+    /// `u8` for floats, or one per component.
+    type Byte;
+
     /// 0 below the threshold and 1 above it
     fn step(self, threshold: Self) -> Self;
     /// false below the threshold and true above it
-    fn bstep(self, threshold: Self) -> bool;
+    fn bstep(self, threshold: Self) -> Self::Bool;
 
     /// Clip into a range, e.g. 0..1
     fn clip<R: Into<Range>>(self, range: R) -> Self;
@@ -17,6 +24,8 @@ pub trait Interp: Sized {
     fn lerp<R: Into<Range>>(self, onto: R) -> Self;
     /// Interpolate self from a range onto 0..1
     fn ilerp<R: Into<Range>>(self, from: R) -> Self;
+    /// Interpolate from self towards `other` by `fr`, without clamping so splines can overshoot
+    fn tween(self, other: Self, fr: f64) -> Self;
     /// Map self from a range onto another range
     fn map<R0: Into<Range>, R1: Into<Range>>(self, from: R0, onto: R1) -> Self {
         self.ilerp(from).lerp(onto)
@@ -59,17 +68,20 @@ pub trait Interp: Sized {
     fn square(self, pd: Self, duty: Self) -> Self;
     fn negsquare(self, pd: Self, duty: Self) -> Self;
     /// Square wave, but booleans
-    fn bsquare(self, pd: Self, duty: Self) -> bool;
+    fn bsquare(self, pd: Self, duty: Self) -> Self::Bool;
 
     fn trapazoid(self, pd: Self, ramp: Self) -> Self;
 
     /// Convert 0..1 to 0..255u8
-    fn byte(self) -> u8;
+    fn byte(self) -> Self::Byte;
     /// Convert 0..1 to 0..127u8
-    fn midi_byte(self) -> u8;
+    fn midi_byte(self) -> Self::Byte;
 }
 
 impl Interp for f64 {
+    type Bool = bool;
+    type Byte = u8;
+
     fn step(self, threshold: f64) -> f64 {
         if self < threshold {
             0.0
@@ -97,6 +109,9 @@ impl Interp for f64 {
         let (i, j) = onto.into().bounds();
         i + self.clamp(0.0, 1.0) * (j - i)
     }
+    fn tween(self, other: f64, fr: f64) -> f64 {
+        self + fr * (other - self)
+    }
     fn ilerp<R: Into<Range>>(self, from: R) -> f64 {
         let (i, j) = from.into().bounds();
         (self - i) / (j - i)
@@ -161,3 +176,201 @@ impl Interp for f64 {
         self.clamp(0.0, 1.0).lerp(0..127) as u8
     }
 }
+
+/// Apply `f` to each component.
+fn each<T: Copy, U, const N: usize>(a: [T; N], f: impl Fn(T) -> U) -> [U; N] {
+    std::array::from_fn(|i| f(a[i]))
+}
+
+/// Apply `f` to each pair of components.
+fn zip<T: Copy, U, const N: usize>(a: [T; N], b: [T; N], f: impl Fn(T, T) -> U) -> [U; N] {
+    std::array::from_fn(|i| f(a[i], b[i]))
+}
+
+/// Apply `f` to each triple of components.
+fn zip3<T: Copy, U, const N: usize>(a: [T; N], b: [T; N], c: [T; N], f: impl Fn(T, T, T) -> U) -> [U; N] {
+    std::array::from_fn(|i| f(a[i], b[i], c[i]))
+}
+
+impl<T: Interp + Copy, const N: usize> Interp for [T; N] {
+    type Bool = [T::Bool; N];
+    type Byte = [T::Byte; N];
+
+    fn step(self, threshold: Self) -> Self {
+        zip(self, threshold, T::step)
+    }
+    fn bstep(self, threshold: Self) -> Self::Bool {
+        zip(self, threshold, T::bstep)
+    }
+
+    fn clip<R: Into<Range>>(self, range: R) -> Self {
+        let range = range.into();
+        each(self, |v| v.clip(range))
+    }
+
+    fn fmod(self, v: Self) -> Self {
+        zip(self, v, T::fmod)
+    }
+    fn fmod_div(self, v: f64) -> Self {
+        each(self, |x| x.fmod_div(v))
+    }
+
+    fn lerp<R: Into<Range>>(self, onto: R) -> Self {
+        let onto = onto.into();
+        each(self, |v| v.lerp(onto))
+    }
+    fn tween(self, other: Self, fr: f64) -> Self {
+        zip(self, other, |a, b| a.tween(b, fr))
+    }
+    fn ilerp<R: Into<Range>>(self, from: R) -> Self {
+        let from = from.into();
+        each(self, |v| v.ilerp(from))
+    }
+    fn phase(self, pd: Self, fr: Self) -> Self {
+        zip3(self, pd, fr, T::phase)
+    }
+
+    fn line(self, slope: Self, intercept: Self) -> Self {
+        zip3(self, slope, intercept, T::line)
+    }
+    fn cover(self, amt: Self) -> Self {
+        zip(self, amt, T::cover)
+    }
+
+    fn ssin(self, pd: Self) -> Self {
+        zip(self, pd, T::ssin)
+    }
+    fn scos(self, pd: Self) -> Self {
+        zip(self, pd, T::scos)
+    }
+    fn fsin(self, pd: Self) -> Self {
+        zip(self, pd, T::fsin)
+    }
+    fn fcos(self, pd: Self) -> Self {
+        zip(self, pd, T::fcos)
+    }
+    fn tri(self, pd: Self) -> Self {
+        zip(self, pd, T::tri)
+    }
+    fn ramp(self, pd: Self) -> Self {
+        zip(self, pd, T::ramp)
+    }
+    fn square(self, pd: Self, duty: Self) -> Self {
+        zip3(self, pd, duty, T::square)
+    }
+    fn negsquare(self, pd: Self, duty: Self) -> Self {
+        zip3(self, pd, duty, T::negsquare)
+    }
+    fn bsquare(self, pd: Self, duty: Self) -> Self::Bool {
+        zip3(self, pd, duty, T::bsquare)
+    }
+
+    fn trapazoid(self, pd: Self, ramp: Self) -> Self {
+        zip3(self, pd, ramp, T::trapazoid)
+    }
+
+    fn byte(self) -> Self::Byte {
+        each(self, T::byte)
+    }
+    fn midi_byte(self) -> Self::Byte {
+        each(self, T::midi_byte)
+    }
+}
+
+/// Implement [`Interp`] by converting to another type that implements it, with `$to` and `$from`.
+macro_rules! impl_interp_via {
+    ($($ty:ty => $via:ty, $to:expr, $from:expr;)*) => {
+        $(
+            impl Interp for $ty {
+                type Bool = <$via as Interp>::Bool;
+                type Byte = <$via as Interp>::Byte;
+
+                fn step(self, threshold: Self) -> Self {
+                    $from($to(self).step($to(threshold)))
+                }
+                fn bstep(self, threshold: Self) -> Self::Bool {
+                    $to(self).bstep($to(threshold))
+                }
+
+                fn clip<R: Into<Range>>(self, range: R) -> Self {
+                    $from($to(self).clip(range))
+                }
+
+                fn fmod(self, v: Self) -> Self {
+                    $from($to(self).fmod($to(v)))
+                }
+                fn fmod_div(self, v: f64) -> Self {
+                    $from($to(self).fmod_div(v))
+                }
+
+                fn lerp<R: Into<Range>>(self, onto: R) -> Self {
+                    $from($to(self).lerp(onto))
+                }
+                fn tween(self, other: Self, fr: f64) -> Self {
+                    $from($to(self).tween($to(other), fr))
+                }
+                fn ilerp<R: Into<Range>>(self, from: R) -> Self {
+                    $from($to(self).ilerp(from))
+                }
+                fn phase(self, pd: Self, fr: Self) -> Self {
+                    $from($to(self).phase($to(pd), $to(fr)))
+                }
+
+                fn line(self, slope: Self, intercept: Self) -> Self {
+                    $from($to(self).line($to(slope), $to(intercept)))
+                }
+                fn cover(self, amt: Self) -> Self {
+                    $from($to(self).cover($to(amt)))
+                }
+
+                fn ssin(self, pd: Self) -> Self {
+                    $from($to(self).ssin($to(pd)))
+                }
+                fn scos(self, pd: Self) -> Self {
+                    $from($to(self).scos($to(pd)))
+                }
+                fn fsin(self, pd: Self) -> Self {
+                    $from($to(self).fsin($to(pd)))
+                }
+                fn fcos(self, pd: Self) -> Self {
+                    $from($to(self).fcos($to(pd)))
+                }
+                fn tri(self, pd: Self) -> Self {
+                    $from($to(self).tri($to(pd)))
+                }
+                fn ramp(self, pd: Self) -> Self {
+                    $from($to(self).ramp($to(pd)))
+                }
+                fn square(self, pd: Self, duty: Self) -> Self {
+                    $from($to(self).square($to(pd), $to(duty)))
+                }
+                fn negsquare(self, pd: Self, duty: Self) -> Self {
+                    $from($to(self).negsquare($to(pd), $to(duty)))
+                }
+                fn bsquare(self, pd: Self, duty: Self) -> Self::Bool {
+                    $to(self).bsquare($to(pd), $to(duty))
+                }
+
+                fn trapazoid(self, pd: Self, ramp: Self) -> Self {
+                    $from($to(self).trapazoid($to(pd), $to(ramp)))
+                }
+
+                fn byte(self) -> Self::Byte {
+                    $to(self).byte()
+                }
+                fn midi_byte(self) -> Self::Byte {
+                    $to(self).midi_byte()
+                }
+            }
+        )*
+    };
+}
+
+impl_interp_via! {
+    f32 => f64, f64::from, |v: f64| v as f32;
+    (f64, f64) => [f64; 2], <[f64; 2]>::from, <(f64, f64)>::from;
+    Vec2 => [f64; 2], <[f64; 2]>::from, Vec2::from;
+    Vec3 => [f64; 3], <[f64; 3]>::from, Vec3::from;
+    Rgb => [f64; 3], <[f64; 3]>::from, Rgb::from;
+    Rgbw => [f64; 4], <[f64; 4]>::from, Rgbw::from;
+}
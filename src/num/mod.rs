@@ -1,12 +1,24 @@
 mod byte;
 mod ease;
+pub mod envelope;
 mod interp;
+pub mod noise;
 mod range;
+pub mod signal;
+pub mod smooth;
+pub mod track;
+mod vec;
 
 pub use byte::Byte;
-pub use ease::Ease;
+pub use ease::{Dir, Ease, Easing, Jump};
+pub use envelope::Envelope;
 pub use interp::Interp;
+pub use noise::Noise;
 pub use range::Range;
+pub use signal::Signal;
+pub use smooth::Filter;
+pub use track::Track;
+pub use vec::{Vec2, Vec3};
 
 pub use std::f64::consts::TAU;
 
@@ -0,0 +1,199 @@
+//! Coherent noise, for smooth randomness like flicker and fire.
+//!
+//! Noise is evaluated in `0.0..1.0` like [`Interp::fsin`](crate::num::Interp::fsin), with features
+//! about one unit apart. Drive fixture groups by sampling at each fixture's position with time
+//! along another axis, e.g. `noise.get3(pos.x, pos.y, t)`.
+
+use crate::num::signal::hash;
+use crate::num::{Signal, Vec3};
+
+/// The noise function summed in each octave.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Basis {
+    /// Random values at each lattice point, smoothly interpolated. Blobby.
+    Value,
+    /// Random gradients at each lattice point. Smooth, with some grid alignment.
+    #[default]
+    Perlin,
+    /// Gradients on a simplex grid. Smooth, with less grid alignment than Perlin.
+    Simplex,
+}
+
+/// Seedable noise with fractal Brownian motion, summing octaves of finer detail.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Noise {
+    pub basis: Basis,
+    pub seed: u64,
+    /// Number of layers, each finer than the last. 1 for plain noise.
+    pub octaves: u32,
+    /// Frequency multiplier for each octave.
+    pub lacunarity: f64,
+    /// Amplitude multiplier for each octave.
+    pub gain: f64,
+}
+
+impl Default for Noise {
+    fn default() -> Self {
+        Self::new(Basis::default(), 0)
+    }
+}
+
+impl Noise {
+    pub fn new(basis: Basis, seed: u64) -> Self {
+        Self { basis, seed, octaves: 1, lacunarity: 2.0, gain: 0.5 }
+    }
+
+    pub fn value(seed: u64) -> Self {
+        Self::new(Basis::Value, seed)
+    }
+
+    pub fn perlin(seed: u64) -> Self {
+        Self::new(Basis::Perlin, seed)
+    }
+
+    pub fn simplex(seed: u64) -> Self {
+        Self::new(Basis::Simplex, seed)
+    }
+
+    // Lower dimensions sample off the lattice planes, where gradient noise is always 0.5 at integers.
+
+    pub fn get1(&self, x: f64) -> f64 {
+        self.get3(x, 0.5, 0.5)
+    }
+
+    pub fn get2(&self, x: f64, y: f64) -> f64 {
+        self.get3(x, y, 0.5)
+    }
+
+    pub fn get3(&self, x: f64, y: f64, z: f64) -> f64 {
+        let (mut sum, mut amp, mut freq, mut total) = (0.0, 1.0, 1.0, 0.0);
+        for i in 0..self.octaves.max(1) {
+            let seed = self.seed.wrapping_add(i as u64);
+            let (x, y, z) = (x * freq, y * freq, z * freq);
+            let n = match self.basis {
+                Basis::Value => value(seed, x, y, z),
+                Basis::Perlin => perlin(seed, x, y, z),
+                Basis::Simplex => simplex(seed, x, y, z),
+            };
+            sum += n * amp;
+            total += amp;
+            amp *= self.gain;
+            freq *= self.lacunarity;
+        }
+        (0.5 + 0.5 * sum / total).clamp(0.0, 1.0)
+    }
+
+    /// Noise at a position, e.g. from `Group::each_pos`.
+    pub fn get(&self, p: Vec3) -> f64 {
+        self.get3(p.x, p.y, p.z)
+    }
+}
+
+impl Signal for Noise {
+    fn at(&self, t: f64) -> f64 {
+        self.get1(t)
+    }
+}
+
+fn lattice(seed: u64, x: i64, y: i64, z: i64) -> u64 {
+    hash(hash(hash(seed ^ x as u64) ^ y as u64) ^ z as u64)
+}
+
+/// Quintic fade, for interpolation with continuous second derivative.
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// Trilinearly interpolate a function of the 8 corners around a point, in -1..1.
+fn cube(x: f64, y: f64, z: f64, corner: impl Fn(i64, i64, i64, f64, f64, f64) -> f64) -> f64 {
+    let (x0, y0, z0) = (x.floor(), y.floor(), z.floor());
+    let (fx, fy, fz) = (x - x0, y - y0, z - z0);
+    let (ix, iy, iz) = (x0 as i64, y0 as i64, z0 as i64);
+    let c = |dx: i64, dy: i64, dz: i64| corner(ix + dx, iy + dy, iz + dz, fx - dx as f64, fy - dy as f64, fz - dz as f64);
+
+    let (u, v, w) = (fade(fx), fade(fy), fade(fz));
+    let x00 = lerp(c(0, 0, 0), c(1, 0, 0), u);
+    let x10 = lerp(c(0, 1, 0), c(1, 1, 0), u);
+    let x01 = lerp(c(0, 0, 1), c(1, 0, 1), u);
+    let x11 = lerp(c(0, 1, 1), c(1, 1, 1), u);
+    lerp(lerp(x00, x10, v), lerp(x01, x11, v), w)
+}
+
+fn value(seed: u64, x: f64, y: f64, z: f64) -> f64 {
+    cube(x, y, z, |ix, iy, iz, _, _, _| (lattice(seed, ix, iy, iz) >> 11) as f64 / (1u64 << 52) as f64 - 1.0)
+}
+
+/// Dot product of an offset with one of the 12 cube edge gradients.
+fn grad(h: u64, x: f64, y: f64, z: f64) -> f64 {
+    match h % 12 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x + z,
+        5 => -x + z,
+        6 => x - z,
+        7 => -x - z,
+        8 => y + z,
+        9 => -y + z,
+        10 => y - z,
+        _ => -y - z,
+    }
+}
+
+fn perlin(seed: u64, x: f64, y: f64, z: f64) -> f64 {
+    cube(x, y, z, |ix, iy, iz, dx, dy, dz| grad(lattice(seed, ix, iy, iz), dx, dy, dz))
+}
+
+/// 3D simplex noise, after Stefan Gustavson's reference implementation.
+fn simplex(seed: u64, x: f64, y: f64, z: f64) -> f64 {
+    const F3: f64 = 1.0 / 3.0;
+    const G3: f64 = 1.0 / 6.0;
+
+    // Skew into simplex cell space, and find which of the six simplices we're in.
+    let s = (x + y + z) * F3;
+    let (i, j, k) = ((x + s).floor(), (y + s).floor(), (z + s).floor());
+    let t = (i + j + k) * G3;
+    let (x0, y0, z0) = (x - (i - t), y - (j - t), z - (k - t));
+
+    let (o1, o2) = if x0 >= y0 {
+        if y0 >= z0 {
+            ((1, 0, 0), (1, 1, 0))
+        } else if x0 >= z0 {
+            ((1, 0, 0), (1, 0, 1))
+        } else {
+            ((0, 0, 1), (1, 0, 1))
+        }
+    } else if y0 < z0 {
+        ((0, 0, 1), (0, 1, 1))
+    } else if x0 < z0 {
+        ((0, 1, 0), (0, 1, 1))
+    } else {
+        ((0, 1, 0), (1, 1, 0))
+    };
+
+    let (i, j, k) = (i as i64, j as i64, k as i64);
+    let corners = [(0, 0, 0), o1, o2, (1, 1, 1)];
+    let sum: f64 = corners
+        .iter()
+        .enumerate()
+        .map(|(n, &(di, dj, dk))| {
+            let g = n as f64 * G3;
+            let (dx, dy, dz) = (x0 - di as f64 + g, y0 - dj as f64 + g, z0 - dk as f64 + g);
+            let t = 0.6 - dx * dx - dy * dy - dz * dz;
+            if t < 0.0 {
+                0.0
+            } else {
+                t.powi(4) * grad(lattice(seed, i + di, j + dj, k + dk), dx, dy, dz)
+            }
+        })
+        .sum();
+
+    32.0 * sum
+}
@@ -1,5 +1,6 @@
 /// A bounded range from `(lo, hi]`
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Range {
     pub lo: f64,
     pub hi: f64,
@@ -37,8 +38,17 @@ macro_rules! impl_from {
                     }
                 }
             }
+
+            impl From<std::ops::RangeInclusive<$ty>> for Range {
+                fn from(r: std::ops::RangeInclusive<$ty>) -> Self {
+                    Self {
+                        lo: *r.start() as f64,
+                        hi: *r.end() as f64,
+                    }
+                }
+            }
         )*
     };
 }
 
-impl_from!(f64, i32);
+impl_from!(f64, f32, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
@@ -0,0 +1,347 @@
+//! Signals: functions of time as values, so effects can be described as data and edited live.
+//!
+//! Waveforms range from `0.0..1.0` over a period `pd` in the same units as `t`, matching the
+//! methods on [`Interp`]. Combinators are built with the provided methods on [`Signal`], e.g.
+//! `Sine::new(2.0).mul(Square::new(0.5, 0.25)).remap(0.2..1.0)`.
+
+use crate::num::{noise, Interp, Range};
+
+/// A value that varies over time.
+pub trait Signal {
+    /// The value at time `t`.
+    fn at(&self, t: f64) -> f64;
+
+    /// Sum with another signal.
+    fn add<S: Signal>(self, other: S) -> Add<Self, S>
+    where
+        Self: Sized,
+    {
+        Add(self, other)
+    }
+
+    /// Product with another signal, e.g. to gate one with a square wave.
+    fn mul<S: Signal>(self, other: S) -> Mul<Self, S>
+    where
+        Self: Sized,
+    {
+        Mul(self, other)
+    }
+
+    /// Shift forward by `fr` of a period `pd`, like [`Interp::phase`].
+    fn shift(self, pd: f64, fr: f64) -> Shift<Self>
+    where
+        Self: Sized,
+    {
+        Shift { signal: self, by: pd * fr }
+    }
+
+    /// Run `by` times faster.
+    fn speed(self, by: f64) -> Speed<Self>
+    where
+        Self: Sized,
+    {
+        Speed { signal: self, by }
+    }
+
+    /// Clamp into a range.
+    fn clamp<R: Into<Range>>(self, range: R) -> Clamp<Self>
+    where
+        Self: Sized,
+    {
+        Clamp { signal: self, range: range.into() }
+    }
+
+    /// Map from `0.0..1.0` onto a range.
+    fn remap<R: Into<Range>>(self, range: R) -> Remap<Self>
+    where
+        Self: Sized,
+    {
+        Remap { signal: self, range: range.into() }
+    }
+}
+
+impl<F: Fn(f64) -> f64> Signal for F {
+    fn at(&self, t: f64) -> f64 {
+        self(t)
+    }
+}
+
+/// Waveforms
+mod wave {
+    use super::*;
+
+    /// A constant value.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Const(pub f64);
+
+    /// Sine, like [`Interp::fsin`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Sine {
+        pub pd: f64,
+    }
+
+    /// Triangle, starting from 0.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Tri {
+        pub pd: f64,
+    }
+
+    /// Rising sawtooth.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Saw {
+        pub pd: f64,
+    }
+
+    /// Square, high for the first `duty` of each period.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Square {
+        pub pd: f64,
+        pub duty: f64,
+    }
+
+    /// Trapezoid, ramping up and down over `ramp` at each end of the period.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Trapezoid {
+        pub pd: f64,
+        pub ramp: f64,
+    }
+
+    /// A new random value each period, the same for the same `seed`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Noise {
+        pub pd: f64,
+        pub seed: u64,
+    }
+
+    /// Any waveform, for choosing the shape at runtime.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Wave {
+        Const(Const),
+        Sine(Sine),
+        Tri(Tri),
+        Saw(Saw),
+        Square(Square),
+        Trapezoid(Trapezoid),
+        Noise(Noise),
+        /// Coherent noise, see [`noise::Noise`].
+        Smooth(noise::Noise),
+    }
+
+    impl Sine {
+        pub fn new(pd: f64) -> Self {
+            Self { pd }
+        }
+    }
+
+    impl Tri {
+        pub fn new(pd: f64) -> Self {
+            Self { pd }
+        }
+    }
+
+    impl Saw {
+        pub fn new(pd: f64) -> Self {
+            Self { pd }
+        }
+    }
+
+    impl Square {
+        pub fn new(pd: f64, duty: f64) -> Self {
+            Self { pd, duty }
+        }
+    }
+
+    impl Trapezoid {
+        pub fn new(pd: f64, ramp: f64) -> Self {
+            Self { pd, ramp }
+        }
+    }
+
+    impl Noise {
+        pub fn new(pd: f64, seed: u64) -> Self {
+            Self { pd, seed }
+        }
+    }
+
+    impl Signal for Const {
+        fn at(&self, _t: f64) -> f64 {
+            self.0
+        }
+    }
+
+    impl Signal for Sine {
+        fn at(&self, t: f64) -> f64 {
+            t.fsin(self.pd)
+        }
+    }
+
+    impl Signal for Tri {
+        fn at(&self, t: f64) -> f64 {
+            t.tri(self.pd)
+        }
+    }
+
+    impl Signal for Saw {
+        fn at(&self, t: f64) -> f64 {
+            t.ramp(self.pd)
+        }
+    }
+
+    impl Signal for Square {
+        fn at(&self, t: f64) -> f64 {
+            t.square(self.pd, self.duty)
+        }
+    }
+
+    impl Signal for Trapezoid {
+        fn at(&self, t: f64) -> f64 {
+            t.fmod(self.pd).trapazoid(self.pd, self.ramp)
+        }
+    }
+
+    impl Signal for Noise {
+        fn at(&self, t: f64) -> f64 {
+            let i = (t / self.pd).floor() as i64 as u64;
+            (hash(i ^ self.seed.rotate_left(32)) >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    impl Signal for Wave {
+        fn at(&self, t: f64) -> f64 {
+            match self {
+                Wave::Const(s) => s.at(t),
+                Wave::Sine(s) => s.at(t),
+                Wave::Tri(s) => s.at(t),
+                Wave::Saw(s) => s.at(t),
+                Wave::Square(s) => s.at(t),
+                Wave::Trapezoid(s) => s.at(t),
+                Wave::Noise(s) => s.at(t),
+                Wave::Smooth(s) => s.at(t),
+            }
+        }
+    }
+
+    macro_rules! impl_wave_from {
+        ($($ty:ident),*) => {
+            $(
+                impl From<$ty> for Wave {
+                    fn from(s: $ty) -> Self {
+                        Wave::$ty(s)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_wave_from!(Const, Sine, Tri, Saw, Square, Trapezoid, Noise);
+
+    impl From<noise::Noise> for Wave {
+        fn from(s: noise::Noise) -> Self {
+            Wave::Smooth(s)
+        }
+    }
+
+    /// SplitMix64, to scatter neighboring periods across the output range.
+    pub(crate) fn hash(mut x: u64) -> u64 {
+        x = x.wrapping_add(0x9e3779b97f4a7c15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+        x ^ (x >> 31)
+    }
+}
+
+/// Combinators
+mod comb {
+    use super::*;
+
+    /// Sum of two signals, see [`Signal::add`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Add<A, B>(pub A, pub B);
+
+    /// Product of two signals, see [`Signal::mul`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Mul<A, B>(pub A, pub B);
+
+    /// A signal shifted in time, see [`Signal::shift`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Shift<S> {
+        pub signal: S,
+        pub by: f64,
+    }
+
+    /// A signal sped up or slowed down, see [`Signal::speed`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Speed<S> {
+        pub signal: S,
+        pub by: f64,
+    }
+
+    /// A signal clamped into a range, see [`Signal::clamp`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Clamp<S> {
+        pub signal: S,
+        pub range: Range,
+    }
+
+    /// A signal mapped onto a range, see [`Signal::remap`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Remap<S> {
+        pub signal: S,
+        pub range: Range,
+    }
+
+    impl<A: Signal, B: Signal> Signal for Add<A, B> {
+        fn at(&self, t: f64) -> f64 {
+            self.0.at(t) + self.1.at(t)
+        }
+    }
+
+    impl<A: Signal, B: Signal> Signal for Mul<A, B> {
+        fn at(&self, t: f64) -> f64 {
+            self.0.at(t) * self.1.at(t)
+        }
+    }
+
+    impl<S: Signal> Signal for Shift<S> {
+        fn at(&self, t: f64) -> f64 {
+            self.signal.at(t + self.by)
+        }
+    }
+
+    impl<S: Signal> Signal for Speed<S> {
+        fn at(&self, t: f64) -> f64 {
+            self.signal.at(t * self.by)
+        }
+    }
+
+    impl<S: Signal> Signal for Clamp<S> {
+        fn at(&self, t: f64) -> f64 {
+            let (lo, hi) = self.range.sort().bounds();
+            self.signal.at(t).clamp(lo, hi)
+        }
+    }
+
+    impl<S: Signal> Signal for Remap<S> {
+        fn at(&self, t: f64) -> f64 {
+            self.signal.at(t).lerp(self.range)
+        }
+    }
+}
+
+pub use comb::{Add, Clamp, Mul, Remap, Shift, Speed};
+pub(crate) use wave::hash;
+pub use wave::{Const, Noise, Saw, Sine, Square, Trapezoid, Tri, Wave};
@@ -0,0 +1,240 @@
+//! Smoothing filters, for taking the steps out of 7-bit MIDI knobs before they reach a moving head.
+//!
+//! Filters are updated with a timestamp in seconds rather than once per frame, so they behave the
+//! same at any frame rate, and keep one filter per parameter:
+//!
+//! ```ignore
+//! let pan = self.pan.update(t, knob.pan);
+//! ```
+//!
+//! On frames without new input, [`Filter::advance`] keeps moving towards the latest value.
+
+use crate::num::TAU;
+
+/// A stateful filter over a stream of timestamped values.
+pub trait Filter {
+    /// Feed in `x` at time `t`, returning the filtered value.
+    fn update(&mut self, t: f64, x: f64) -> f64;
+
+    /// The last filtered value, if there's been any input.
+    fn value(&self) -> Option<f64>;
+
+    /// The latest input, which the filter is moving towards.
+    fn target(&self) -> Option<f64>;
+
+    /// Move towards the latest input at time `t`, for frames without new input.
+    fn advance(&mut self, t: f64) -> Option<f64> {
+        let x = self.target()?;
+        Some(self.update(t, x))
+    }
+
+    /// Forget all input, so the next value passes straight through.
+    fn reset(&mut self);
+}
+
+/// The last input time, output value and input of a filter.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct State {
+    t: f64,
+    x: f64,
+    target: f64,
+}
+
+/// Limits how fast the value can change, in units per second.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Slew {
+    /// Fastest rise per second.
+    pub up: f64,
+    /// Fastest fall per second.
+    pub down: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    state: Option<State>,
+}
+
+/// Critically damped spring: follows the target as fast as it can without overshooting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Spring {
+    /// Roughly how long it takes to catch up to the target, in seconds.
+    pub time: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    state: Option<State>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    vel: f64,
+}
+
+/// Exponential smoothing, moving `1 - 1/e` of the way to the target every `tau` seconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Exp {
+    pub tau: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    state: Option<State>,
+}
+
+/// The one-euro filter, which smooths heavily when the value is still and lightly when it moves.
+///
+/// See Casiez et al., "1€ Filter: A Simple Speed-based Low-pass Filter for Noisy Input in
+/// Interactive Systems". Tune by lowering `min_cutoff` until jitter is gone, then raising `beta`
+/// until fast moves stop lagging.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OneEuro {
+    /// Cutoff frequency in Hz when still.
+    pub min_cutoff: f64,
+    /// How much the cutoff rises with speed.
+    pub beta: f64,
+    /// Cutoff frequency in Hz for the speed estimate.
+    pub d_cutoff: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    state: Option<State>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dx: f64,
+}
+
+impl Slew {
+    pub fn new(rate: f64) -> Self {
+        Self::asym(rate, rate)
+    }
+
+    /// Different rates for rising and falling, e.g. to snap on and fade off.
+    pub fn asym(up: f64, down: f64) -> Self {
+        Self { up, down, state: None }
+    }
+}
+
+impl Spring {
+    pub fn new(time: f64) -> Self {
+        Self { time, state: None, vel: 0.0 }
+    }
+}
+
+impl Exp {
+    pub fn new(tau: f64) -> Self {
+        Self { tau, state: None }
+    }
+}
+
+impl OneEuro {
+    pub fn new(min_cutoff: f64, beta: f64) -> Self {
+        Self { min_cutoff, beta, d_cutoff: 1.0, state: None, dx: 0.0 }
+    }
+}
+
+/// Smoothing factor for a first-order low-pass at `cutoff` Hz over `dt` seconds.
+fn alpha(cutoff: f64, dt: f64) -> f64 {
+    let tau = 1.0 / (TAU * cutoff.max(f64::EPSILON));
+    1.0 / (1.0 + tau / dt)
+}
+
+/// Advance `state` to `(t, x)` with `f(dt, last)`, passing the first value straight through.
+///
+/// Input at the same time as the last replaces the target without moving, and input from the past
+/// is ignored.
+fn step(state: &mut Option<State>, t: f64, target: f64, f: impl FnOnce(f64, f64) -> f64) -> f64 {
+    let x = match *state {
+        Some(s) if t > s.t => f(t - s.t, s.x),
+        Some(s) if t == s.t => s.x,
+        Some(s) => return s.x,
+        None => target,
+    };
+    *state = Some(State { t, x, target });
+    x
+}
+
+impl Filter for Slew {
+    fn update(&mut self, t: f64, x: f64) -> f64 {
+        let (up, down) = (self.up, self.down);
+        step(&mut self.state, t, x, |dt, last| last + (x - last).min(up.max(0.0) * dt).max(-down.max(0.0) * dt))
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.state.map(|s| s.x)
+    }
+
+    fn target(&self) -> Option<f64> {
+        self.state.map(|s| s.target)
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+impl Filter for Spring {
+    fn update(&mut self, t: f64, x: f64) -> f64 {
+        let (time, vel) = (self.time.max(f64::EPSILON), &mut self.vel);
+        step(&mut self.state, t, x, |dt, last| {
+            // Closed-form approximation from Game Programming Gems 4, "Critically Damped Ease-In/Ease-Out Smoothing".
+            let omega = 2.0 / time;
+            let w = omega * dt;
+            let decay = 1.0 / (1.0 + w + 0.48 * w * w + 0.235 * w * w * w);
+            let change = last - x;
+            let temp = (*vel + omega * change) * dt;
+            *vel = (*vel - omega * temp) * decay;
+            x + (change + temp) * decay
+        })
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.state.map(|s| s.x)
+    }
+
+    fn target(&self) -> Option<f64> {
+        self.state.map(|s| s.target)
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+        self.vel = 0.0;
+    }
+}
+
+impl Filter for Exp {
+    fn update(&mut self, t: f64, x: f64) -> f64 {
+        let tau = self.tau;
+        step(&mut self.state, t, x, |dt, last| {
+            let a = if tau > 0.0 { 1.0 - (-dt / tau).exp() } else { 1.0 };
+            last + a * (x - last)
+        })
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.state.map(|s| s.x)
+    }
+
+    fn target(&self) -> Option<f64> {
+        self.state.map(|s| s.target)
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+impl Filter for OneEuro {
+    fn update(&mut self, t: f64, x: f64) -> f64 {
+        let Self { min_cutoff, beta, d_cutoff, .. } = *self;
+        let dx = &mut self.dx;
+        step(&mut self.state, t, x, |dt, last| {
+            let a = alpha(d_cutoff, dt);
+            *dx += a * ((x - last) / dt - *dx);
+            let a = alpha(min_cutoff + beta * dx.abs(), dt);
+            last + a * (x - last)
+        })
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.state.map(|s| s.x)
+    }
+
+    fn target(&self) -> Option<f64> {
+        self.state.map(|s| s.target)
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+        self.dx = 0.0;
+    }
+}
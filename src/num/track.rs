@@ -0,0 +1,169 @@
+//! Keyframe tracks, for choreographing values over time, e.g. a moving head's pan and tilt:
+//!
+//! ```ignore
+//! let track = Track::new(vec![
+//!     Key::new(0.0, (0.0, 0.5), Curve::CatmullRom),
+//!     Key::new(2.0, (0.5, 1.0), Curve::Ease(Easing::EASE_IN_OUT)),
+//!     Key::new(4.0, (1.0, 0.5), Curve::Step),
+//! ]);
+//! let (pan, tilt) = track.at(t).unwrap_or_default();
+//! ```
+//!
+//! Keys can hold anything [`Interp`] works on: floats, pairs, arrays, vectors and colors.
+
+use crate::num::{Ease, Easing, Interp, Signal};
+
+/// How a track moves from one key to the next.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Curve<V> {
+    /// Hold the value until the next key.
+    Step,
+    #[default]
+    Linear,
+    /// Interpolate along an easing curve.
+    Ease(Easing),
+    /// A smooth spline through the neighboring keys.
+    CatmullRom,
+    /// A cubic Bézier with two control values, leaving this key and entering the next.
+    Bezier(V, V),
+}
+
+/// A value at a point in time, and the curve to the next key.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Key<V> {
+    pub t: f64,
+    pub value: V,
+    pub curve: Curve<V>,
+}
+
+/// Keys sorted by time. Holds the first value before the first key, and the last after the last.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "V: serde::Deserialize<'de>")))]
+pub struct Track<V> {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "sorted"))]
+    keys: Vec<Key<V>>,
+}
+
+impl<V> Key<V> {
+    pub fn new(t: f64, value: V, curve: Curve<V>) -> Self {
+        Self { t, value, curve }
+    }
+
+    pub fn linear(t: f64, value: V) -> Self {
+        Self::new(t, value, Curve::Linear)
+    }
+}
+
+impl<V: Interp + Copy> Track<V> {
+    pub fn new(mut keys: Vec<Key<V>>) -> Self {
+        sort(&mut keys);
+        Self { keys }
+    }
+
+    pub fn keys(&self) -> &[Key<V>] {
+        &self.keys
+    }
+
+    /// Add a key, replacing any already at the same time.
+    pub fn insert(&mut self, key: Key<V>) {
+        match self.keys.binary_search_by(|k| k.t.total_cmp(&key.t)) {
+            Ok(i) => self.keys[i] = key,
+            Err(i) => self.keys.insert(i, key),
+        }
+    }
+
+    /// Remove and return the key at index `i`.
+    pub fn remove(&mut self, i: usize) -> Key<V> {
+        self.keys.remove(i)
+    }
+
+    /// Time of the last key.
+    pub fn duration(&self) -> f64 {
+        self.keys.last().map_or(0.0, |k| k.t)
+    }
+
+    /// The value at time `t`, or `None` if there are no keys.
+    pub fn at(&self, t: f64) -> Option<V> {
+        let keys = &self.keys;
+        let i = keys.partition_point(|k| k.t <= t);
+        if i == 0 {
+            return keys.first().map(|k| k.value);
+        }
+        if i == keys.len() {
+            return keys.last().map(|k| k.value);
+        }
+
+        let (k1, k2) = (&keys[i - 1], &keys[i]);
+        let span = k2.t - k1.t;
+        let fr = if span > 0.0 { (t - k1.t) / span } else { 1.0 };
+        let (a, b) = (k1.value, k2.value);
+
+        Some(match k1.curve {
+            Curve::Step => a,
+            Curve::Linear => a.tween(b, fr),
+            Curve::Ease(easing) => a.tween(b, fr.ease(easing)),
+            Curve::Bezier(c1, c2) => {
+                // De Casteljau, which only needs interpolation.
+                let (p, q, r) = (a.tween(c1, fr), c1.tween(c2, fr), c2.tween(b, fr));
+                let (p, q) = (p.tween(q, fr), q.tween(r, fr));
+                p.tween(q, fr)
+            }
+            Curve::CatmullRom => {
+                // Mirror the spacing at the ends, holding the end values.
+                let k0 = match i.checked_sub(2) {
+                    Some(j) => (keys[j].t, keys[j].value),
+                    None => (k1.t - span, a),
+                };
+                let k3 = match keys.get(i + 1) {
+                    Some(k) => (k.t, k.value),
+                    None => (k2.t + span, b),
+                };
+                catmull_rom(k0, (k1.t, a), (k2.t, b), k3, t)
+            }
+        })
+    }
+}
+
+impl<V: Interp + Copy> From<Vec<Key<V>>> for Track<V> {
+    fn from(keys: Vec<Key<V>>) -> Self {
+        Self::new(keys)
+    }
+}
+
+impl Signal for Track<f64> {
+    fn at(&self, t: f64) -> f64 {
+        Track::at(self, t).unwrap_or_default()
+    }
+}
+
+fn sort<V>(keys: &mut [Key<V>]) {
+    keys.sort_by(|a, b| a.t.total_cmp(&b.t));
+}
+
+/// Sort keys when loading, since a track file may not list them in order.
+#[cfg(feature = "serde")]
+fn sorted<'de, D, V>(d: D) -> Result<Vec<Key<V>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    V: serde::Deserialize<'de>,
+{
+    let mut keys: Vec<Key<V>> = serde::Deserialize::deserialize(d)?;
+    sort(&mut keys);
+    Ok(keys)
+}
+
+/// Catmull-Rom spline through keys at their own times, by the Barry-Goldman pyramid.
+fn catmull_rom<V: Interp + Copy>(k0: (f64, V), k1: (f64, V), k2: (f64, V), k3: (f64, V), t: f64) -> V {
+    let fr = |t0: f64, t1: f64| if t1 > t0 { (t - t0) / (t1 - t0) } else { 1.0 };
+    let ((t0, p0), (t1, p1), (t2, p2), (t3, p3)) = (k0, k1, k2, k3);
+
+    let a1 = p0.tween(p1, fr(t0, t1));
+    let a2 = p1.tween(p2, fr(t1, t2));
+    let a3 = p2.tween(p3, fr(t2, t3));
+    let b1 = a1.tween(a2, fr(t0, t2));
+    let b2 = a2.tween(a3, fr(t1, t3));
+    b1.tween(b2, fr(t1, t2))
+}
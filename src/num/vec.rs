@@ -0,0 +1,232 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// A 2D point or direction, e.g. pan and tilt.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vec2 {
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+    pub const ONE: Self = Self::new(1.0, 1.0);
+    pub const X: Self = Self::new(1.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0);
+
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Dot product.
+    pub fn dot(self, rhs: Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// Euclidean length.
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, rhs: Self) -> Self {
+        Self::new(self.x.min(rhs.x), self.y.min(rhs.y))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, rhs: Self) -> Self {
+        Self::new(self.x.max(rhs.x), self.y.max(rhs.y))
+    }
+
+    /// Apply a function to each component.
+    pub fn map(self, f: impl Fn(f64) -> f64) -> Self {
+        Self::new(f(self.x), f(self.y))
+    }
+}
+
+/// A 3D point or direction.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vec3 {
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+    pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Dot product.
+    pub fn dot(self, rhs: Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Cross product.
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(self.y * rhs.z - self.z * rhs.y, self.z * rhs.x - self.x * rhs.z, self.x * rhs.y - self.y * rhs.x)
+    }
+
+    /// Euclidean length.
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, rhs: Self) -> Self {
+        Self::new(self.x.min(rhs.x), self.y.min(rhs.y), self.z.min(rhs.z))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, rhs: Self) -> Self {
+        Self::new(self.x.max(rhs.x), self.y.max(rhs.y), self.z.max(rhs.z))
+    }
+
+    /// Apply a function to each component.
+    pub fn map(self, f: impl Fn(f64) -> f64) -> Self {
+        Self::new(f(self.x), f(self.y), f(self.z))
+    }
+}
+
+/// Conversions from 1D, 2D and 3D coordinates.
+mod conv {
+    use super::*;
+
+    impl From<(f64, f64)> for Vec2 {
+        fn from((x, y): (f64, f64)) -> Self {
+            Self::new(x, y)
+        }
+    }
+
+    impl From<[f64; 2]> for Vec2 {
+        fn from([x, y]: [f64; 2]) -> Self {
+            Self::new(x, y)
+        }
+    }
+
+    impl From<Vec2> for [f64; 2] {
+        fn from(v: Vec2) -> Self {
+            [v.x, v.y]
+        }
+    }
+
+    impl From<Vec2> for Vec3 {
+        fn from(v: Vec2) -> Self {
+            Self::new(v.x, v.y, 0.0)
+        }
+    }
+
+    impl From<f64> for Vec3 {
+        fn from(x: f64) -> Self {
+            Self::new(x, 0.0, 0.0)
+        }
+    }
+
+    impl From<(f64, f64)> for Vec3 {
+        fn from((x, y): (f64, f64)) -> Self {
+            Self::new(x, y, 0.0)
+        }
+    }
+
+    impl From<(f64, f64, f64)> for Vec3 {
+        fn from((x, y, z): (f64, f64, f64)) -> Self {
+            Self::new(x, y, z)
+        }
+    }
+
+    impl From<[f64; 3]> for Vec3 {
+        fn from([x, y, z]: [f64; 3]) -> Self {
+            Self::new(x, y, z)
+        }
+    }
+
+    impl From<Vec3> for [f64; 3] {
+        fn from(v: Vec3) -> Self {
+            [v.x, v.y, v.z]
+        }
+    }
+}
+
+/// Operators
+mod ops {
+    use super::*;
+
+    impl Add for Vec2 {
+        type Output = Vec2;
+        fn add(self, rhs: Vec2) -> Vec2 {
+            Self::new(self.x + rhs.x, self.y + rhs.y)
+        }
+    }
+    impl AddAssign for Vec2 {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl Sub for Vec2 {
+        type Output = Vec2;
+        fn sub(self, rhs: Vec2) -> Vec2 {
+            Self::new(self.x - rhs.x, self.y - rhs.y)
+        }
+    }
+    impl SubAssign for Vec2 {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl Mul<f64> for Vec2 {
+        type Output = Vec2;
+        fn mul(self, fr: f64) -> Vec2 {
+            self.map(|v| v * fr)
+        }
+    }
+    impl MulAssign<f64> for Vec2 {
+        fn mul_assign(&mut self, rhs: f64) {
+            *self = *self * rhs;
+        }
+    }
+
+    impl Add for Vec3 {
+        type Output = Vec3;
+        fn add(self, rhs: Vec3) -> Vec3 {
+            Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+        }
+    }
+    impl AddAssign for Vec3 {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl Sub for Vec3 {
+        type Output = Vec3;
+        fn sub(self, rhs: Vec3) -> Vec3 {
+            Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+        }
+    }
+    impl SubAssign for Vec3 {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl Mul<f64> for Vec3 {
+        type Output = Vec3;
+        fn mul(self, fr: f64) -> Vec3 {
+            self.map(|v| v * fr)
+        }
+    }
+    impl MulAssign<f64> for Vec3 {
+        fn mul_assign(&mut self, rhs: f64) {
+            *self = *self * rhs;
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Clocks, in seconds as `f64` to feed straight into [`Signal`](crate::num::Signal)s and envelopes.
+//!
+//! Drive a render loop from [`Frames`], and swap [`Real`] for [`Virtual`] to render faster than
+//! real time or step through a cue frame by frame:
+//!
+//! ```ignore
+//! let clock = Rc::new(Virtual::new());
+//! let mut frames = Frames::new(clock.clone());
+//! for _ in 0..FPS * 60 {
+//!     clock.advance(1.0 / FPS as f64);
+//!     render(frames.tick());
+//! }
+//! ```
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A source of the current time.
+pub trait Clock {
+    /// Seconds since the clock started.
+    fn now(&self) -> f64;
+}
+
+/// Wall-clock time, from when it was created.
+#[derive(Clone, Copy, Debug)]
+pub struct Real {
+    start: Instant,
+}
+
+/// Time that only moves when told to, for tests and offline rendering.
+///
+/// Advancing takes `&self`, so one clock can be shared through an `Rc` with everything reading it.
+#[derive(Clone, Debug, Default)]
+pub struct Virtual {
+    t: Cell<f64>,
+}
+
+/// One tick of a render loop.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Frame {
+    /// Number of frames before this one.
+    pub n: u64,
+    /// Seconds since the clock started.
+    pub t: f64,
+    /// Seconds since the last frame, or 0 for the first.
+    pub dt: f64,
+}
+
+/// Frame numbering and delta bookkeeping over a clock.
+#[derive(Clone, Debug)]
+pub struct Frames<C> {
+    pub clock: C,
+    /// Longest `dt` to report, so a stall doesn't make effects jump, e.g. `Some(0.1)`.
+    pub max_dt: Option<f64>,
+    last: Option<Frame>,
+}
+
+impl Real {
+    pub fn new() -> Self {
+        Self { start: Instant::now() }
+    }
+}
+
+impl Default for Real {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for Real {
+    fn now(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+}
+
+impl Virtual {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start at `t` seconds.
+    pub fn at(t: f64) -> Self {
+        Self { t: Cell::new(t) }
+    }
+
+    /// Move forward by `dt` seconds.
+    pub fn advance(&self, dt: f64) {
+        self.t.set(self.t.get() + dt.max(0.0));
+    }
+
+    /// Jump to `t` seconds, forwards or backwards.
+    pub fn set(&self, t: f64) {
+        self.t.set(t);
+    }
+}
+
+impl Clock for Virtual {
+    fn now(&self) -> f64 {
+        self.t.get()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> f64 {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Rc<C> {
+    fn now(&self) -> f64 {
+        (**self).now()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> f64 {
+        (**self).now()
+    }
+}
+
+impl<C: Clock> Frames<C> {
+    pub fn new(clock: C) -> Self {
+        Self { clock, max_dt: None, last: None }
+    }
+
+    /// Read the clock for the next frame.
+    pub fn tick(&mut self) -> Frame {
+        let t = self.clock.now();
+        let frame = match self.last {
+            Some(last) => {
+                let dt = (t - last.t).max(0.0);
+                Frame { n: last.n + 1, t, dt: self.max_dt.map_or(dt, |max| dt.min(max)) }
+            }
+            None => Frame { n: 0, t, dt: 0.0 },
+        };
+        self.last = Some(frame);
+        frame
+    }
+
+    /// The most recent frame.
+    pub fn last(&self) -> Option<Frame> {
+        self.last
+    }
+
+    /// Start counting frames again from 0.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
@@ -0,0 +1,6 @@
+//! Time, modeled explicitly so effects can run against a real clock on stage and a stepped one offline.
+
+pub mod clock;
+pub mod tempo;
+pub use clock::{Clock, Frame, Frames, Real, Virtual};
+pub use tempo::{Quantize, Signature, Tempo, Trigger};
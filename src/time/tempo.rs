@@ -0,0 +1,282 @@
+//! Musical time: beats and bars at a tempo, set by hand or by tapping along.
+//!
+//! Phases are in `0.0..1.0`, ready to shape with a period of 1, e.g.
+//! `tempo.beat(t).fsin(1.0)` for a pulse on every beat, or `tempo.bar(t).square(1.0, 0.25)` to
+//! flash on each downbeat.
+
+use std::fmt;
+
+/// Beats per minute that tap tempo will settle on.
+pub const BPM: std::ops::RangeInclusive<f64> = 20.0..=300.0;
+
+/// Taps further apart than this start a new count.
+const TAP_TIMEOUT: f64 = 2.0;
+/// Taps remembered for averaging.
+const TAP_COUNT: usize = 8;
+/// Intervals further than this fraction from the median are ignored as missed or double taps.
+const TAP_TOLERANCE: f64 = 0.2;
+/// Slack in beats when finding boundaries, so a time set exactly on one isn't rounded before it.
+const EPSILON: f64 = 1e-9;
+
+/// A time signature, e.g. 4/4 or 6/8.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Signature {
+    /// Beats in a bar.
+    pub beats: u32,
+    /// The note value that counts as a beat, and that BPM counts, e.g. 8 for eighth notes.
+    pub unit: u32,
+}
+
+/// A grid to wait for, see [`Tempo::next`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Quantize {
+    Beat,
+    Bar,
+    /// A number of beats, e.g. 0.5 for off-beats or 16.0 for a phrase.
+    Beats(f64),
+}
+
+/// A tempo and the phase of its beats and bars over time.
+#[derive(Clone, Debug)]
+pub struct Tempo {
+    bpm: f64,
+    signature: Signature,
+    /// When beat 0 fell, in seconds.
+    origin: f64,
+    /// The beat the current signature's bars count from.
+    bar_origin: f64,
+    /// A signature change waiting for its first bar, and the beat that bar starts on.
+    pending: Option<(f64, Signature)>,
+    taps: Vec<f64>,
+}
+
+/// Something waiting to happen on a quantized boundary, e.g. a cue on the next bar.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Trigger {
+    at: Option<f64>,
+}
+
+impl Signature {
+    pub const FOUR_FOUR: Self = Self::new(4, 4);
+    pub const THREE_FOUR: Self = Self::new(3, 4);
+    pub const SIX_EIGHT: Self = Self::new(6, 8);
+
+    pub const fn new(beats: u32, unit: u32) -> Self {
+        Self { beats, unit }
+    }
+}
+
+impl Default for Signature {
+    fn default() -> Self {
+        Self::FOUR_FOUR
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.beats, self.unit)
+    }
+}
+
+impl Quantize {
+    /// Length in beats for a signature.
+    pub fn beats(self, signature: Signature) -> f64 {
+        match self {
+            Quantize::Beat => 1.0,
+            Quantize::Bar => signature.beats.max(1) as f64,
+            Quantize::Beats(n) => n,
+        }
+    }
+}
+
+impl Tempo {
+    /// Start at `bpm` in 4/4, with beat 0 at time 0.
+    pub fn new(bpm: f64) -> Self {
+        Self {
+            bpm: bpm.max(f64::EPSILON),
+            signature: Signature::default(),
+            origin: 0.0,
+            bar_origin: 0.0,
+            pending: None,
+            taps: vec![],
+        }
+    }
+
+    pub fn bpm(&self) -> f64 {
+        self.bpm
+    }
+
+    /// Change tempo at time `t`, without jumping the phase.
+    pub fn set_bpm(&mut self, t: f64, bpm: f64) {
+        let beats = self.beats(t);
+        self.bpm = bpm.max(f64::EPSILON);
+        self.origin = t - beats * self.beat_len();
+    }
+
+    /// The signature in effect at time `t`, counting a change once its bar starts.
+    pub fn signature(&self, t: f64) -> Signature {
+        self.grid(t).1
+    }
+
+    /// Change time signature from the next bar after `t`, keeping the current one until then.
+    pub fn set_signature(&mut self, t: f64, signature: Signature) {
+        self.apply_pending(t);
+        self.pending = Some((self.next_beats(t, Quantize::Bar), signature));
+    }
+
+    /// Seconds per beat, e.g. as the period for [`Interp`](crate::num::Interp) waves.
+    pub fn beat_len(&self) -> f64 {
+        60.0 / self.bpm
+    }
+
+    /// Seconds per bar at time `t`.
+    pub fn bar_len(&self, t: f64) -> f64 {
+        self.beat_len() * Quantize::Bar.beats(self.signature(t))
+    }
+
+    /// Beats since beat 0, including the fraction of the current one.
+    pub fn beats(&self, t: f64) -> f64 {
+        (t - self.origin) / self.beat_len()
+    }
+
+    /// Bars since the signature last changed, including the fraction of the current one.
+    pub fn bars(&self, t: f64) -> f64 {
+        let (bar_origin, signature) = self.grid(t);
+        (self.beats(t) - bar_origin) / Quantize::Bar.beats(signature)
+    }
+
+    /// Phase through the current beat, in `0.0..1.0`.
+    pub fn beat(&self, t: f64) -> f64 {
+        self.beats(t).rem_euclid(1.0)
+    }
+
+    /// Phase through the current bar, in `0.0..1.0`.
+    pub fn bar(&self, t: f64) -> f64 {
+        self.bars(t).rem_euclid(1.0)
+    }
+
+    /// Which beat of the bar it is, counting from 0.
+    pub fn beat_in_bar(&self, t: f64) -> u32 {
+        let (bar_origin, signature) = self.grid(t);
+        let beats = (self.beats(t) - bar_origin + EPSILON).floor();
+        beats.rem_euclid(Quantize::Bar.beats(signature)) as u32
+    }
+
+    /// Register a tap at time `t`, setting the tempo from recent taps and putting a beat on this one.
+    pub fn tap(&mut self, t: f64) {
+        if self.taps.last().is_some_and(|&last| t - last > TAP_TIMEOUT || t < last) {
+            self.taps.clear();
+        }
+        if self.taps.len() == TAP_COUNT {
+            self.taps.remove(0);
+        }
+        self.taps.push(t);
+
+        let mut intervals: Vec<f64> = self.taps.windows(2).map(|w| w[1] - w[0]).collect();
+        if !intervals.is_empty() {
+            intervals.sort_by(f64::total_cmp);
+            let median = intervals[intervals.len() / 2];
+            let near: Vec<f64> = intervals.into_iter().filter(|i| (i - median).abs() <= median * TAP_TOLERANCE).collect();
+            let mean = near.iter().sum::<f64>() / near.len() as f64;
+            self.bpm = (60.0 / mean).clamp(*BPM.start(), *BPM.end());
+        }
+
+        self.resync(t, Quantize::Beat);
+    }
+
+    /// Forget taps, so the next starts a new count.
+    pub fn clear_taps(&mut self) {
+        self.taps.clear();
+    }
+
+    /// Shift the phase by `by` seconds, positive to move later, to line up with the music by ear.
+    pub fn nudge(&mut self, by: f64) {
+        self.origin += by;
+    }
+
+    /// Snap the nearest boundary of `q` to time `t`, e.g. `Quantize::Bar` on a downbeat.
+    pub fn resync(&mut self, t: f64, q: Quantize) {
+        self.apply_pending(t);
+        let len = q.beats(self.signature);
+        if len <= 0.0 {
+            return;
+        }
+        let base = if q == Quantize::Bar { self.bar_origin } else { 0.0 };
+        let beats = self.beats(t);
+        let off = beats - base - ((beats - base) / len).round() * len;
+        self.origin += off * self.beat_len();
+    }
+
+    /// Time of the next boundary of `q` strictly after `t`.
+    pub fn next(&self, t: f64, q: Quantize) -> f64 {
+        self.origin + self.next_beats(t, q) * self.beat_len()
+    }
+
+    /// Beat number of the next boundary of `q` strictly after `t`.
+    fn next_beats(&self, t: f64, q: Quantize) -> f64 {
+        let (bar_origin, signature) = self.grid(t);
+        let len = q.beats(signature);
+        if len <= 0.0 {
+            return self.beats(t);
+        }
+        let base = if q == Quantize::Bar { bar_origin } else { 0.0 };
+        base + (((self.beats(t) - base) / len + EPSILON).floor() + 1.0) * len
+    }
+
+    /// The bar origin and signature in effect at time `t`, counting a pending change once its bar starts.
+    fn grid(&self, t: f64) -> (f64, Signature) {
+        match self.pending {
+            Some((at, signature)) if self.beats(t) + EPSILON >= at => (at, signature),
+            _ => (self.bar_origin, self.signature),
+        }
+    }
+
+    /// Make a pending signature change current once time `t` reaches its bar.
+    fn apply_pending(&mut self, t: f64) {
+        if let Some((at, signature)) = self.pending {
+            if self.beats(t) + EPSILON >= at {
+                (self.bar_origin, self.signature) = (at, signature);
+                self.pending = None;
+            }
+        }
+    }
+}
+
+impl Default for Tempo {
+    fn default() -> Self {
+        Self::new(120.0)
+    }
+}
+
+impl Trigger {
+    /// Wait for time `at`, e.g. from [`Tempo::next`].
+    pub fn arm(&mut self, at: f64) {
+        self.at = Some(at);
+    }
+
+    pub fn cancel(&mut self) {
+        self.at = None;
+    }
+
+    pub fn is_armed(&self) -> bool {
+        self.at.is_some()
+    }
+
+    /// When it will fire, if armed.
+    pub fn at(&self) -> Option<f64> {
+        self.at
+    }
+
+    /// True once, on the first poll at or after the armed time.
+    pub fn poll(&mut self, t: f64) -> bool {
+        match self.at {
+            Some(at) if t >= at => {
+                self.at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
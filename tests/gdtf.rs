@@ -0,0 +1,122 @@
+use stagebridge::dmx::gdtf::{Fixture, FixtureType};
+use stagebridge::dmx::{Device, HasPanTilt, HasStrobe};
+
+const DESCRIPTION: &str = include_str!("data/description.xml");
+
+fn fixture_type() -> FixtureType {
+    FixtureType::parse(DESCRIPTION).unwrap()
+}
+
+fn encode(fixture: &Fixture) -> Vec<u8> {
+    let mut buf = vec![0; fixture.channels()];
+    fixture.encode(&mut buf);
+    buf
+}
+
+#[test]
+fn parses_fixture_type() {
+    let ty = fixture_type();
+    assert_eq!(ty.name, "Test Spot");
+    assert_eq!(ty.manufacturer, "Stagebridge");
+    assert_eq!(ty.geometries.len(), 1);
+    assert_eq!(ty.geometries[0].children[0].children[0].name, "Head");
+    assert_eq!(ty.geometries[0].children[0].children[0].kind, "Axis");
+}
+
+#[test]
+fn mode_footprint() {
+    let ty = fixture_type();
+    assert_eq!(ty.mode("Standard").unwrap().footprint(), 9);
+    assert_eq!(ty.mode("Basic").unwrap().footprint(), 3);
+    assert!(ty.mode("Extended").is_none());
+}
+
+#[test]
+fn offsets_and_resolution() {
+    let ty = fixture_type();
+    let mode = ty.mode("Standard").unwrap();
+    assert_eq!(mode.channels[0].offset, [0, 1]);
+    assert_eq!(mode.channels[0].resolution(), 2);
+    assert_eq!(mode.channels[0].max(), 0xffff);
+    assert_eq!(mode.channels[2].offset, [4]);
+    assert_eq!(mode.channels[2].max(), 0xff);
+}
+
+#[test]
+fn values_scale_to_resolution() {
+    let ty = fixture_type();
+    let mode = ty.mode("Standard").unwrap();
+    // Shifted up, mirrored up, and scaled down.
+    assert_eq!(mode.channels[0].default, 0x8000);
+    assert_eq!(mode.channels[1].default, 0x8080);
+    assert_eq!(mode.channels[2].default, 0xff);
+}
+
+#[test]
+fn function_ranges() {
+    let ty = fixture_type();
+    let mode = ty.mode("Standard").unwrap();
+
+    let pan = mode.channels[0].function("Pan").unwrap();
+    assert_eq!((pan.from, pan.to), (0, 0xffff));
+    assert_eq!((pan.physical.lo, pan.physical.hi), (-270.0, 270.0));
+
+    // Functions from both logical channels, each running until the next starts.
+    let shutter = &mode.channels[3];
+    assert_eq!(shutter.attribute, "Shutter1");
+    assert_eq!(shutter.functions.len(), 2);
+    let closed = shutter.function("Shutter1").unwrap();
+    assert_eq!((closed.from, closed.to), (0, 63));
+    let strobe = shutter.function("Shutter1Strobe").unwrap();
+    assert_eq!((strobe.from, strobe.to), (64, 255));
+
+    let sets: Vec<_> = closed.sets.iter().map(|s| (s.name.as_str(), s.from, s.to)).collect();
+    assert_eq!(sets, [("Closed", 0, 31), ("Open", 32, 63)]);
+}
+
+#[test]
+fn encodes_defaults() {
+    let ty = fixture_type();
+    let fixture = Fixture::new(&ty, "Standard").unwrap();
+    assert_eq!(encode(&fixture), [0x80, 0x00, 0x80, 0x80, 255, 32, 0, 0, 0]);
+
+    let fixture = Fixture::new(&ty, "Basic").unwrap();
+    assert_eq!(encode(&fixture), [128, 128, 255]);
+}
+
+#[test]
+fn encodes_attributes() {
+    let ty = fixture_type();
+    let mut fixture = Fixture::new(&ty, "Standard").unwrap();
+
+    fixture.set_pan(1.0);
+    fixture.set_tilt(0.0);
+    fixture.set("Dimmer", 0.5);
+    fixture.set("ColorAdd_G", 1.0);
+    assert_eq!(encode(&fixture), [0xff, 0xff, 0, 0, 128, 32, 0, 255, 0]);
+    assert_eq!(fixture.pan(), 1.0);
+
+    fixture.set_strobe(1.0);
+    assert_eq!(encode(&fixture)[5], 255);
+    fixture.set_strobe(0.0);
+    assert_eq!(encode(&fixture)[5], 32);
+    assert!(!fixture.preset("Shutter1", "Half"));
+
+    // Out of range channels are ignored.
+    fixture.raw(2, 300);
+    fixture.raw(20, 1);
+    assert_eq!(encode(&fixture)[4], 255);
+}
+
+#[test]
+fn rejects_bad_offsets() {
+    for offset in ["0", "1,x", "1,2,3,4,5"] {
+        let xml = DESCRIPTION.replacen(r#"Offset="1,2""#, &format!("Offset=\"{offset}\""), 1);
+        assert!(FixtureType::parse(&xml).is_err(), "{offset}");
+    }
+}
+
+#[test]
+fn unknown_mode() {
+    assert!(Fixture::new(&fixture_type(), "Extended").is_err());
+}